@@ -47,6 +47,6 @@ pub struct Thread {
 #[allow(dead_code)]
 pub struct ThreadListAdapter {}
 
-impl list::Adapter for ThreadListAdapter {
+unsafe impl list::Adapter for ThreadListAdapter {
     const LINK_OFFSET: usize = offset_of!(Thread, link);
 }
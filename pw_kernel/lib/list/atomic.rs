@@ -0,0 +1,186 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+//! A lock-free list for handing elements from many producers to a consumer.
+//!
+//! [`AtomicList`] is a Treiber stack: `push` swings the head to the new
+//! element with a compare-and-swap and `pop_all` takes the whole chain by
+//! swapping the head with null.
+//!
+//! Only the head is atomic.  A producer writes the element's `next` link
+//! before publishing the element with a `Release` compare-and-swap, and the
+//! consumer reads the chain only after taking it with an `Acquire` swap.  Once
+//! published, an element's links are not touched again until it has been
+//! taken by a consumer, so the links themselves need no synchronization.
+//!
+//! # ABA
+//!
+//! The classic ABA problem for Treiber stacks comes from popping a single
+//! element: a consumer reads `head` and `head.next`, is preempted while the
+//! head is popped and re-pushed, and then successfully swaps in a stale
+//! `next`.  `AtomicList` does not offer a single-element pop.  `pop_all` swaps
+//! the head unconditionally and never reads a link before taking ownership of
+//! the chain.  `push` only compares the head it linked the new element to, so
+//! if the head is popped and re-pushed in between, the new element is still
+//! correctly linked in front of it.
+//!
+//! # Concurrent pushes
+//!
+//! Every element pushed by any number of producers comes out of `pop_all`,
+//! with the elements of each producer in the order it pushed them, even while
+//! the consumer drains the list concurrently.  The example below doubles as
+//! the module's concurrency test.  loom is not available, so it runs on real
+//! threads and only covers the interleavings the host scheduler happens to
+//! produce; it does not explore them exhaustively.
+//! ```
+//! # use core::mem::offset_of;
+//! # use list::{atomic::AtomicList, Adapter, Link};
+//! # #[repr(C)]
+//! # struct Record {
+//! #     producer: usize,
+//! #     seq: usize,
+//! #     link: Link,
+//! # }
+//! # struct RecordAdapter {}
+//! # unsafe impl Adapter for RecordAdapter {
+//! #     const LINK_OFFSET: usize = offset_of!(Record, link);
+//! # }
+//! const PRODUCERS: usize = 4;
+//! const RECORDS: usize = 25;
+//! static QUEUE: AtomicList<Record, RecordAdapter> = AtomicList::new();
+//!
+//! let producers: Vec<_> = (0..PRODUCERS)
+//!     .map(|producer| {
+//!         std::thread::spawn(move || {
+//!             for seq in 0..RECORDS {
+//!                 let record = Box::leak(Box::new(Record { producer, seq, link: Link::new() }));
+//!                 unsafe { QUEUE.push(record) };
+//!             }
+//!         })
+//!     })
+//!     .collect();
+//!
+//! let mut next_seq = [0; PRODUCERS];
+//! let mut taken = 0;
+//! while taken < PRODUCERS * RECORDS {
+//!     let mut records = QUEUE.pop_all();
+//!     if unsafe { records.is_empty() } {
+//!         std::thread::yield_now();
+//!     }
+//!     while let Some(record) = unsafe { records.pop_head() } {
+//!         assert_eq!(record.seq, next_seq[record.producer]);
+//!         next_seq[record.producer] += 1;
+//!         taken += 1;
+//!         drop(unsafe { Box::from_raw(record) });
+//!     }
+//! }
+//!
+//! for producer in producers {
+//!     producer.join().unwrap();
+//! }
+//! assert_eq!(next_seq, [RECORDS; PRODUCERS]);
+//! assert!(QUEUE.is_empty());
+//! ```
+
+use core::marker::PhantomData;
+use core::ptr::{self, NonNull};
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::{Adapter, Link, UnsafeList};
+
+pub struct AtomicList<T, A: Adapter> {
+    head: AtomicPtr<Link>,
+    _phantom_type: PhantomData<T>,
+    _phantom_adapter: PhantomData<A>,
+}
+
+// Safety: Elements are handed from the pushing thread to the thread calling
+// `pop_all` so `T` must be `Send`.  All accesses to `head` are atomic and the
+// element links are only touched by the thread that currently owns the
+// element.
+unsafe impl<T: Send, A: Adapter> Send for AtomicList<T, A> {}
+unsafe impl<T: Send, A: Adapter> Sync for AtomicList<T, A> {}
+
+impl<T, A: Adapter> AtomicList<T, A> {
+    pub const fn new() -> Self {
+        Self {
+            head: AtomicPtr::new(ptr::null_mut()),
+            _phantom_type: PhantomData,
+            _phantom_adapter: PhantomData,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.load(Ordering::Relaxed).is_null()
+    }
+
+    /// Pushes `element` onto the list.  May be called concurrently from any
+    /// number of threads or interrupt handlers.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure the element is not in a list and is
+    /// not accessed, moved, or dropped until it has been taken by `pop_all`.
+    pub unsafe fn push(&self, element: &mut T) {
+        let element_ptr = UnsafeList::<T, A>::get_link_ptr_mut(element);
+        (*element_ptr.as_ptr()).set_prev(None);
+
+        let mut head = self.head.load(Ordering::Relaxed);
+        loop {
+            // The element is not yet visible to other threads so its link can
+            // be written without synchronization.
+            (*element_ptr.as_ptr()).set_next(NonNull::new(head));
+
+            match self.head.compare_exchange_weak(
+                head,
+                element_ptr.as_ptr(),
+                Ordering::Release,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return,
+                Err(current) => head = current,
+            }
+        }
+    }
+
+    /// Takes every element pushed so far and returns them in an `UnsafeList`
+    /// in the order they were pushed.
+    ///
+    /// Elements pushed concurrently with `pop_all` are either part of the
+    /// returned list or remain in `self` for the next call.
+    pub fn pop_all(&self) -> UnsafeList<T, A> {
+        let mut cur = NonNull::new(self.head.swap(ptr::null_mut(), Ordering::Acquire));
+        let mut list = UnsafeList::new();
+
+        // Safety: The `Acquire` swap above synchronizes with the `Release`
+        // compare-and-swap of every `push` in the taken chain, so the links
+        // are visible and no other thread can reach the taken elements.
+        unsafe {
+            while let Some(cur_ptr) = cur {
+                // The chain runs from the most to the least recently pushed
+                // element.  Pushing each to the front of `list` restores push
+                // order and rewrites both links in the process.
+                cur = (*cur_ptr.as_ptr()).get_next();
+                list.push_front_unchecked(&mut *UnsafeList::<T, A>::get_element_mut(cur_ptr));
+            }
+        }
+
+        list
+    }
+}
+
+impl<T, A: Adapter> Default for AtomicList<T, A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+//! An [`UnsafeList`] with a capacity fixed at compile time.
+//!
+//! [`BoundedList`] never holds more than `N` elements.  Pushing onto a full
+//! list hands the element back to the caller, unlinked, instead of growing the
+//! list.
+
+use crate::{Adapter, UnsafeList};
+
+pub struct BoundedList<T, A: Adapter, const N: usize> {
+    list: UnsafeList<T, A>,
+}
+
+impl<T, A: Adapter, const N: usize> BoundedList<T, A, N> {
+    pub const fn new() -> Self {
+        Self {
+            list: UnsafeList::new(),
+        }
+    }
+
+    /// Returns the maximum number of elements the list can hold.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn is_full(&self) -> bool {
+        self.list.len() >= N
+    }
+
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Pushes `element` onto the front of the list, or returns it untouched
+    /// if the list is full.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure the element is not in a list
+    pub unsafe fn push_front<'a>(&mut self, element: &'a mut T) -> Result<(), &'a mut T> {
+        if self.is_full() {
+            return Err(element);
+        }
+
+        self.list.push_front_unchecked(element);
+        Ok(())
+    }
+
+    /// Pushes `element` onto the back of the list, or returns it untouched
+    /// if the list is full.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure the element is not in a list
+    pub unsafe fn push_back<'a>(&mut self, element: &'a mut T) -> Result<(), &'a mut T> {
+        if self.is_full() {
+            return Err(element);
+        }
+
+        self.list.push_back_unchecked(element);
+        Ok(())
+    }
+
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure the element is in the list
+    pub unsafe fn unlink_element(&mut self, element: &T) {
+        self.list.unlink_element(element)
+    }
+
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn pop_head<'a>(&mut self) -> Option<&'a mut T> {
+        self.list.pop_head()
+    }
+
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn pop_tail<'a>(&mut self) -> Option<&'a mut T> {
+        self.list.pop_tail()
+    }
+
+    /// Returns the underlying list for read-only operations.
+    pub fn as_list(&self) -> &UnsafeList<T, A> {
+        &self.list
+    }
+}
+
+impl<T, A: Adapter, const N: usize> Default for BoundedList<T, A, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,233 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+//! An intrusive list whose members form a ring.
+//!
+//! In a [`CircularList`] the tail's `next` link points to the head and the
+//! head's `prev` link points to the tail, so a [`Cursor`] can be advanced
+//! indefinitely without checking for the end of the list.  Only the head is
+//! stored; the tail is always `head.prev`.
+//!
+//! Because no link is ever `None` while an element is in the ring, walks over
+//! the list are bounded by its length rather than by a terminating link.
+
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+
+use crate::{Adapter, Link, UnsafeList};
+
+pub struct CircularList<T, A: Adapter> {
+    head: Option<NonNull<Link>>,
+    len: usize,
+    _phantom_type: PhantomData<T>,
+    _phantom_adapter: PhantomData<A>,
+}
+
+impl<T, A: Adapter> CircularList<T, A> {
+    pub const fn new() -> Self {
+        Self {
+            head: None,
+            len: 0,
+            _phantom_type: PhantomData,
+            _phantom_adapter: PhantomData,
+        }
+    }
+
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn len(&self) -> usize {
+        self.len
+    }
+
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure the element is not in a list
+    pub unsafe fn push_back(&mut self, element: &mut T) {
+        let element_ptr = UnsafeList::<T, A>::get_link_ptr_mut(element);
+
+        match self.head {
+            // If `head` is `None`, the list is empty and the added element
+            // forms a ring of its own.
+            None => {
+                (*element_ptr.as_ptr()).set_next_in_ring(Some(element_ptr));
+                (*element_ptr.as_ptr()).set_prev_in_ring(Some(element_ptr));
+                self.head = Some(element_ptr);
+            }
+
+            // Otherwise link the added element in between the tail and the
+            // head.
+            Some(head_ptr) => {
+                let tail_ptr = (*head_ptr.as_ptr()).get_prev();
+                (*element_ptr.as_ptr()).set_next_in_ring(Some(head_ptr));
+                (*element_ptr.as_ptr()).set_prev_in_ring(tail_ptr);
+                if let Some(tail_ptr) = tail_ptr {
+                    (*tail_ptr.as_ptr()).set_next_in_ring(Some(element_ptr));
+                }
+                (*head_ptr.as_ptr()).set_prev_in_ring(Some(element_ptr));
+            }
+        }
+
+        self.len += 1;
+    }
+
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure the element is not in a list
+    pub unsafe fn push_front(&mut self, element: &mut T) {
+        // Linking in front of the head and behind the tail is the same place
+        // in a ring.  Only the head differs, and it becomes the new tail.
+        self.push_back(element);
+        self.head = self
+            .head
+            .and_then(|head_ptr| (*head_ptr.as_ptr()).get_prev());
+    }
+
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure the element is in the list
+    pub unsafe fn unlink_element(&mut self, element: &T) {
+        let element_ptr = UnsafeList::<T, A>::get_link_ptr(element);
+
+        if self.len == 1 {
+            // Element is the only member of the list.
+            self.head = None;
+        } else {
+            let prev = (*element_ptr.as_ptr()).get_prev();
+            let next = (*element_ptr.as_ptr()).get_next();
+            if let Some(prev_ptr) = prev {
+                (*prev_ptr.as_ptr()).set_next_in_ring(next);
+            }
+            if let Some(next_ptr) = next {
+                (*next_ptr.as_ptr()).set_prev_in_ring(prev);
+            }
+            if self.head == Some(element_ptr) {
+                self.head = next;
+            }
+        }
+
+        (*element_ptr.as_ptr()).mark_unlinked();
+        self.len -= 1;
+    }
+
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn pop_head<'a>(&mut self) -> Option<&'a mut T> {
+        let element = &mut *UnsafeList::<T, A>::get_element_mut(self.head?);
+        self.unlink_element(element);
+        Some(element)
+    }
+
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn peek_head<'a>(&self) -> Option<&'a T> {
+        Some(&*UnsafeList::<T, A>::get_element_ptr(self.head?))
+    }
+
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn peek_tail<'a>(&self) -> Option<&'a T> {
+        let tail_ptr = (*self.head?.as_ptr()).get_prev()?;
+        Some(&*UnsafeList::<T, A>::get_element_ptr(tail_ptr))
+    }
+
+    /// Calls `callback` for each member once, starting at the head.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn for_each<E, F: FnMut(&T) -> Result<(), E>>(
+        &self,
+        mut callback: F,
+    ) -> Result<(), E> {
+        let mut cur = self.head;
+        for _ in 0..self.len {
+            let Some(cur_ptr) = cur else {
+                break;
+            };
+
+            callback(&*UnsafeList::<T, A>::get_element_ptr(cur_ptr))?;
+            cur = (*cur_ptr.as_ptr()).get_next();
+        }
+
+        Ok(())
+    }
+
+    /// Returns a cursor positioned at the head.  The cursor wraps around from
+    /// the tail to the head and never reaches an end.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members for the lifetime of the cursor.
+    pub unsafe fn cursor(&self) -> Cursor<'_, T, A> {
+        Cursor {
+            current: self.head,
+            _phantom_list: PhantomData,
+        }
+    }
+}
+
+impl<T, A: Adapter> Default for CircularList<T, A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct Cursor<'a, T, A: Adapter> {
+    current: Option<NonNull<Link>>,
+    _phantom_list: PhantomData<&'a CircularList<T, A>>,
+}
+
+impl<'a, T, A: Adapter> Cursor<'a, T, A> {
+    /// Returns the element at the cursor or `None` if the list is empty.
+    pub fn current(&self) -> Option<&'a T> {
+        // Safety: The cursor borrows the list and the caller of `cursor`
+        // guarantees exclusive access to its members.
+        self.current
+            .map(|ptr| unsafe { &*UnsafeList::<T, A>::get_element_ptr(ptr) })
+    }
+
+    /// Moves the cursor to the next element, wrapping from the tail to the
+    /// head.
+    pub fn move_next(&mut self) {
+        // Safety: The cursor borrows the list and the caller of `cursor`
+        // guarantees exclusive access to its members.
+        if let Some(cur_ptr) = self.current {
+            self.current = unsafe { (*cur_ptr.as_ptr()).get_next() };
+        }
+    }
+
+    /// Moves the cursor to the previous element, wrapping from the head to
+    /// the tail.
+    pub fn move_prev(&mut self) {
+        // Safety: The cursor borrows the list and the caller of `cursor`
+        // guarantees exclusive access to its members.
+        if let Some(cur_ptr) = self.current {
+            self.current = unsafe { (*cur_ptr.as_ptr()).get_prev() };
+        }
+    }
+}
@@ -14,10 +14,25 @@
 #![no_std]
 #![cfg_attr(test, no_main)]
 #![allow(dead_code)]
+// The `defmt` feature is enabled through Bazel's `crate_features`, which
+// Cargo's `check-cfg` does not know about.
+#![allow(unexpected_cfgs)]
 use core::cell::UnsafeCell;
+use core::cmp::Ordering;
 use core::marker::PhantomData;
 use core::ptr::NonNull;
 
+// `AtomicList` needs compare-and-swap which is not available on all targets
+// (e.g. Cortex-M0).
+#[cfg(target_has_atomic = "ptr")]
+pub mod atomic;
+pub mod bounded;
+pub mod circular;
+pub mod monitored;
+pub mod safe;
+
+pub use list_derive::Adapter;
+
 // Intrusive link structures are particularly tricky in Rust because mutable
 // references are expected to be globally unique.  Accessing the data through
 // other methods is UB.  There is a good writeup of Tokio's soundness challenges
@@ -88,6 +103,15 @@ unsafe fn set_element(inner: &UnsafeCell<LinkInner>, offset: usize, value: Optio
 }
 
 impl Link {
+    // In debug builds a link taken out of a list has both of its links set to
+    // this misaligned, recognizable address rather than `None`, so following
+    // a stale link faults instead of quietly reading another element.
+    #[cfg(debug_assertions)]
+    const POISON: Option<NonNull<Link>> =
+        NonNull::new(core::ptr::without_provenance_mut(0xdead_beef));
+    #[cfg(not(debug_assertions))]
+    const POISON: Option<NonNull<Link>> = None;
+
     pub const fn new() -> Self {
         Self {
             inner: UnsafeCell::new(LinkInner::new()),
@@ -95,21 +119,116 @@ impl Link {
     }
 
     pub fn is_unlinked(&self) -> bool {
-        self.get_next().is_none() && self.get_prev().is_none()
+        let is_cleared = |link: Option<NonNull<Link>>| link.is_none() || link == Self::POISON;
+        is_cleared(self.get_next()) && is_cleared(self.get_prev())
+    }
+
+    /// Returns true if the link was taken out of a list and poisoned.  Links
+    /// are only poisoned in debug builds, so this is always false in release
+    /// builds.
+    pub fn is_poisoned(&self) -> bool {
+        cfg!(debug_assertions) && self.get_next() == Self::POISON && self.get_prev() == Self::POISON
     }
 
     pub fn is_linked(&self) -> bool {
         !self.is_unlinked()
     }
 
+    /// Detaches the link from its neighbors without access to the list
+    /// header and clears its own links.
+    ///
+    /// The list header is NOT updated.  The header's head and tail can not be
+    /// repaired from here, so this may only be used on interior members of a
+    /// list.  The header's length goes stale and must be repaired by calling
+    /// `UnsafeList::note_unlinked` once for every link removed this way
+    /// before the list is used again, e.g. after the traversal that removed
+    /// them.
+    ///
+    /// Calling this on a link that is already unlinked does nothing and needs
+    /// no repair.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure the link is neither the head nor the
+    /// tail of its list, and to call `note_unlinked` on the list before using
+    /// it again
+    pub unsafe fn unlink_self(&mut self) {
+        // A link that is already unlinked may be poisoned, and its poisoned
+        // links must not be followed.
+        let is_neighbor = |link: &NonNull<Link>| Some(*link) != Self::POISON;
+        let prev = self.get_prev().filter(is_neighbor);
+        let next = self.get_next().filter(is_neighbor);
+
+        if let Some(prev_ptr) = prev {
+            (*prev_ptr.as_ptr()).set_next(next);
+        }
+        if let Some(next_ptr) = next {
+            (*next_ptr.as_ptr()).set_prev(prev);
+        }
+
+        self.mark_unlinked();
+    }
+
+    /// Links `node` in between `prev` and `next`, pointing its links at them
+    /// and theirs back at it.  `None` on either side leaves `node` as the end
+    /// of the chain on that side.
+    ///
+    /// No list header is updated.  This is the building block the list
+    /// methods use to link elements; callers are responsible for their own
+    /// header bookkeeping, e.g. the head, tail and length of an `UnsafeList`.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to all three links.
+    /// It is up to the caller to ensure `node` is not in a list and that any
+    /// `prev` and `next` given are adjacent
+    pub unsafe fn link_between(
+        prev: Option<NonNull<Link>>,
+        node: NonNull<Link>,
+        next: Option<NonNull<Link>>,
+    ) {
+        (*node.as_ptr()).set_prev(prev);
+        (*node.as_ptr()).set_next(next);
+
+        if let Some(prev_ptr) = prev {
+            (*prev_ptr.as_ptr()).set_next(Some(node));
+        }
+        if let Some(next_ptr) = next {
+            (*next_ptr.as_ptr()).set_prev(Some(node));
+        }
+    }
+
+    /// Clears both links of a link that has been taken out of its list.
+    #[inline]
+    fn mark_unlinked(&mut self) {
+        self.set_next(Self::POISON);
+        self.set_prev(Self::POISON);
+    }
+
     #[inline]
     fn get_next(&self) -> Option<NonNull<Link>> {
         unsafe { get_element(&self.inner, LinkInner::NEXT_OFFSET) }
     }
 
+    /// A link pointing at itself makes every walk over the list spin forever.
+    /// Catch it when the link is written rather than when the list hangs.  The
+    /// check is a `debug_assert!`, so release builds only document it:
+    #[cfg_attr(debug_assertions, doc = "```should_panic")]
+    #[cfg_attr(not(debug_assertions), doc = "```no_run")]
+    /// # use core::ptr::NonNull;
+    /// # use list::Link;
+    /// let mut link = Link::new();
+    /// let link_ptr = NonNull::from(&mut link);
+    /// // Panics with "link points at itself".
+    /// unsafe { Link::link_between(None, link_ptr, Some(link_ptr)) };
+    /// ```
     #[inline]
     fn set_next(&mut self, value: Option<NonNull<Link>>) {
-        unsafe { set_element(&self.inner, LinkInner::NEXT_OFFSET, value) }
+        debug_assert!(
+            value != Some(NonNull::from(&*self)),
+            "link points at itself"
+        );
+        self.set_next_in_ring(value)
     }
 
     #[inline]
@@ -119,10 +238,41 @@ impl Link {
 
     #[inline]
     fn set_prev(&mut self, value: Option<NonNull<Link>>) {
+        debug_assert!(
+            value != Some(NonNull::from(&*self)),
+            "link points at itself"
+        );
+        self.set_prev_in_ring(value)
+    }
+
+    // The sole member of a ring is its own neighbor, so ring code writes its
+    // links without the self-reference check.
+    #[inline]
+    fn set_next_in_ring(&mut self, value: Option<NonNull<Link>>) {
+        unsafe { set_element(&self.inner, LinkInner::NEXT_OFFSET, value) }
+    }
+
+    #[inline]
+    fn set_prev_in_ring(&mut self, value: Option<NonNull<Link>>) {
         unsafe { set_element(&self.inner, LinkInner::PREV_OFFSET, value) }
     }
 }
 
+// Safety: A `Link` only holds pointers to other links and has no thread
+// affinity of its own.  Whoever moves an element to another thread must have
+// exclusive access to it, which the list APIs already require of anyone
+// touching its links.  This lets elements be handed between threads (e.g.
+// through `AtomicList`) as long as the element type is otherwise `Send`.
+unsafe impl Send for Link {}
+
+// Safety: A shared `&Link` only allows reading the link.  Every write goes
+// through `&mut Link` or through link pointers inside the unsafe list APIs,
+// whose callers must already ensure exclusive access to the list and its
+// members, so readers on other threads never race with a writer unless that
+// contract is broken.  This lets an element type that is otherwise `Sync` be
+// `Sync`, which sharing `&UnsafeList` between threads requires.
+unsafe impl Sync for Link {}
+
 impl Default for Link {
     fn default() -> Self {
         Self::new()
@@ -133,15 +283,342 @@ impl Default for Link {
 // pointers to the base list node.  This means that there are never pointers to
 // `UnsafeList` and the same care is not needed to avoid mutable references as
 // is taken with the `Link` structure.
+/// An intrusive doubly linked list of `T`s.
+///
+/// An `UnsafeList` can be moved to another thread, for instance behind a
+/// lock:
+/// ```
+/// # use core::mem::offset_of;
+/// # use std::sync::{Arc, Mutex};
+/// # use list::{Adapter, Link, UnsafeList};
+/// # #[repr(C)]
+/// # struct Node {
+/// #     value: u32,
+/// #     link: Link,
+/// # }
+/// # struct NodeAdapter {}
+/// # unsafe impl Adapter for NodeAdapter {
+/// #     const LINK_OFFSET: usize = offset_of!(Node, link);
+/// # }
+/// let node: &'static mut Node = Box::leak(Box::new(Node { value: 1, link: Link::new() }));
+/// let list = Arc::new(Mutex::new(UnsafeList::<Node, NodeAdapter>::new()));
+/// unsafe { list.lock().unwrap().push_back_unchecked(node) };
+///
+/// let shared = list.clone();
+/// std::thread::spawn(move || {
+///     let head = unsafe { shared.lock().unwrap().pop_head() };
+///     assert_eq!(head.map(|node| node.value), Some(1));
+/// })
+/// .join()
+/// .unwrap();
+/// ```
+///
+/// While no thread modifies it, a list of `Sync` elements can also be walked
+/// from several threads at once:
+/// ```
+/// # use core::mem::offset_of;
+/// # use list::{Adapter, Link, UnsafeList};
+/// # #[repr(C)]
+/// # struct Node {
+/// #     value: u32,
+/// #     link: Link,
+/// # }
+/// # struct NodeAdapter {}
+/// # unsafe impl Adapter for NodeAdapter {
+/// #     const LINK_OFFSET: usize = offset_of!(Node, link);
+/// # }
+/// let mut nodes = [1, 2, 3].map(|value| Node { value, link: Link::new() });
+/// let mut list = UnsafeList::<Node, NodeAdapter>::new();
+/// for node in nodes.iter_mut() {
+///     unsafe { list.push_back_unchecked(node) };
+/// }
+///
+/// let list = &list;
+/// std::thread::scope(|scope| {
+///     let walkers: Vec<_> = (0..2)
+///         .map(|_| scope.spawn(|| unsafe { list.iter() }.map(|node| node.value).sum::<u32>()))
+///         .collect();
+///     for walker in walkers {
+///         assert_eq!(walker.join().unwrap(), 6);
+///     }
+/// });
+/// ```
 pub struct UnsafeList<T, A: Adapter> {
     head: Option<NonNull<Link>>,
     tail: Option<NonNull<Link>>,
+    len: usize,
+    max_len: usize,
     _phantom_type: PhantomData<T>,
     _phantom_adapter: PhantomData<A>,
 }
 
-pub trait Adapter {
+// Safety: The list header only holds pointers to the links of its members.
+// Moving the header to another thread hands that thread the ability to reach
+// and mutate the members, so `T` must be `Send`.  Exclusive access to the list
+// and its members is already the caller's responsibility for every method, and
+// that contract does not depend on which thread the caller is on.
+unsafe impl<T: Send, A: Adapter> Send for UnsafeList<T, A> {}
+
+// Safety: The only things reachable through `&UnsafeList` are the unsafe
+// read-only walks (`iter`, `peek_head`, `for_each`, ...) which hand out `&T`,
+// so `T` must be `Sync`.  The caller is still responsible for ensuring no
+// thread mutates the list or its members while these walks are in progress.
+// `Link` is `Sync`, so element types whose other fields are `Sync` meet the
+// bound without opting in.
+unsafe impl<T: Sync, A: Adapter> Sync for UnsafeList<T, A> {}
+
+/// Describes where the `Link` lives inside of `T`.
+///
+/// An element can be a member of several lists at once by giving it a `Link`
+/// for each list and a separate adapter type for each of those links:
+/// ```
+/// use core::mem::offset_of;
+/// use list::{Adapter, Link, UnsafeList};
+///
+/// #[repr(C)]
+/// struct Task {
+///     all_tasks_link: Link,
+///     ready_link: Link,
+/// }
+///
+/// struct AllTasks {}
+/// unsafe impl Adapter for AllTasks {
+///     const LINK_OFFSET: usize = offset_of!(Task, all_tasks_link);
+/// }
+///
+/// struct Ready {}
+/// unsafe impl Adapter for Ready {
+///     const LINK_OFFSET: usize = offset_of!(Task, ready_link);
+/// }
+///
+/// let all_tasks = UnsafeList::<Task, AllTasks>::new();
+/// let ready = UnsafeList::<Task, Ready>::new();
+/// ```
+///
+/// Rather than computing `LINK_OFFSET` by hand, `Adapter` can be derived for
+/// a `#[repr(C)]` struct by marking its `Link` field with `#[list_link]`.  The
+/// struct then serves as its own adapter:
+/// ```
+/// use list::{Adapter, Link, UnsafeList};
+///
+/// #[derive(Adapter)]
+/// #[repr(C)]
+/// struct Node {
+///     value: u32,
+///     #[list_link]
+///     link: Link,
+/// }
+///
+/// assert_eq!(Node::LINK_OFFSET, core::mem::offset_of!(Node, link));
+/// let list = UnsafeList::<Node, Node>::new();
+/// ```
+///
+/// Deriving for a struct without `#[repr(C)]` is rejected:
+/// ```compile_fail
+/// use list::{Adapter, Link};
+///
+/// #[derive(Adapter)]
+/// struct Node {
+///     value: u32,
+///     #[list_link]
+///     link: Link,
+/// }
+/// ```
+///
+/// As is marking a field that is not a `Link`:
+/// ```compile_fail,E0308
+/// use list::{Adapter, Link};
+///
+/// #[derive(Adapter)]
+/// #[repr(C)]
+/// struct Node {
+///     #[list_link]
+///     value: u32,
+///     link: Link,
+/// }
+/// ```
+///
+/// Including one that only points at a `Link`:
+/// ```compile_fail,E0308
+/// use list::{Adapter, Link};
+///
+/// #[derive(Adapter)]
+/// #[repr(C)]
+/// struct Node {
+///     #[list_link]
+///     link: &'static Link,
+///     value: u64,
+/// }
+/// ```
+///
+/// Elements are found from their links with plain pointer arithmetic, so `T`
+/// must be `Sized` and lists of `dyn Trait` are not supported directly.
+/// Elements of different types that share a trait can instead be linked
+/// through a common header that each element embeds and that points back at
+/// its element as a trait object.
+///
+/// A reference to the header only grants access to the header, so the pointer
+/// stored in it and the reference pushed onto the list must both be derived
+/// from one pointer to the whole element.  Casting the header reference the
+/// list hands out back to its element would be undefined behavior:
+/// ```
+/// use core::ptr::NonNull;
+/// use list::{Adapter, Link, UnsafeList};
+///
+/// trait Schedulable {
+///     fn priority(&self) -> u32;
+/// }
+///
+/// #[derive(Adapter)]
+/// #[repr(C)]
+/// struct TaskHeader {
+///     #[list_link]
+///     link: Link,
+///     task: Option<NonNull<dyn Schedulable>>,
+/// }
+///
+/// impl TaskHeader {
+///     const fn new() -> Self {
+///         Self { link: Link::new(), task: None }
+///     }
+///
+///     /// Returns the task containing the header.
+///     fn get(&self) -> &dyn Schedulable {
+///         let task = self.task.expect("header is not bound to a task");
+///         // Safety: `task` is set before the header is pushed onto a list and
+///         // points at the live task containing the header.
+///         unsafe { task.as_ref() }
+///     }
+/// }
+///
+/// struct IdleTask {
+///     header: TaskHeader,
+/// }
+///
+/// impl Schedulable for IdleTask {
+///     fn priority(&self) -> u32 {
+///         0
+///     }
+/// }
+///
+/// let mut idle = IdleTask { header: TaskHeader::new() };
+/// let mut ready = UnsafeList::<TaskHeader, TaskHeader>::new();
+///
+/// let idle_ptr = NonNull::from(&mut idle);
+/// unsafe {
+///     (*idle_ptr.as_ptr()).header.task = Some(idle_ptr as NonNull<dyn Schedulable>);
+///     ready.push_back(&mut (*idle_ptr.as_ptr()).header);
+/// }
+/// let head = unsafe { ready.peek_head() };
+/// assert_eq!(head.map(|header| header.get().priority()), Some(0));
+/// ```
+///
+/// # Safety
+/// `LINK_OFFSET` must be the byte offset of a `Link` field within the element
+/// type the adapter is used with.  The list's pointer math, including that of
+/// the safe wrappers, is only sound if this holds.
+pub unsafe trait Adapter {
     const LINK_OFFSET: usize;
+
+    /// The size of the type `LINK_OFFSET` is an offset into.
+    ///
+    /// Derived adapters set this to the size of their struct and assert
+    /// `verify_adapter` next to the generated impl, so a derived adapter whose
+    /// link does not fit does not compile.  Hand-written adapters may leave
+    /// the default, in which case only the check lists make against their
+    /// element type applies.  See `verify_layout`.
+    const ADAPTER_TYPE_SIZE: usize = usize::MAX;
+}
+
+/// Returns true if `A::LINK_OFFSET` leaves room for a whole `Link` within
+/// `A::ADAPTER_TYPE_SIZE` bytes and is aligned for a `Link`.
+///
+/// Unlike `verify_layout` this needs no element type, so it can be checked
+/// where the adapter is implemented, before any list uses it.  The derive
+/// does so for every non-generic struct, which rejects a link that a packed
+/// struct leaves misaligned:
+/// ```compile_fail,E0080
+/// use list::{Adapter, Link};
+///
+/// #[derive(Adapter)]
+/// #[repr(C, packed)]
+/// struct Node {
+///     value: u8,
+///     #[list_link]
+///     link: Link,
+/// }
+/// ```
+///
+/// Hand-written adapters can opt in the same way:
+/// ```compile_fail,E0080
+/// # use list::{verify_adapter, Adapter, Link};
+/// # #[repr(C)]
+/// # struct Node {
+/// #     value: u32,
+/// #     link: Link,
+/// # }
+/// struct NodeAdapter {}
+/// unsafe impl Adapter for NodeAdapter {
+///     const LINK_OFFSET: usize = core::mem::size_of::<Node>();
+///     const ADAPTER_TYPE_SIZE: usize = core::mem::size_of::<Node>();
+/// }
+///
+/// const _: () = assert!(verify_adapter::<NodeAdapter>());
+/// ```
+pub const fn verify_adapter<A: Adapter>() -> bool {
+    let Some(link_end) = A::LINK_OFFSET.checked_add(core::mem::size_of::<Link>()) else {
+        return false;
+    };
+
+    link_end <= A::ADAPTER_TYPE_SIZE && A::LINK_OFFSET % core::mem::align_of::<Link>() == 0
+}
+
+/// Returns true if `A::LINK_OFFSET` leaves room for a whole `Link` inside of a
+/// `T` and is aligned for a `Link`.
+///
+/// This can not prove the offset points at a `Link` but it catches offsets
+/// left stale by changes to `T` in many cases.  It can be checked at compile
+/// time:
+/// ```
+/// # use core::mem::offset_of;
+/// # use list::{verify_layout, Adapter, Link};
+/// # #[repr(C)]
+/// # struct Node {
+/// #     value: u32,
+/// #     link: Link,
+/// # }
+/// # struct NodeAdapter {}
+/// # unsafe impl Adapter for NodeAdapter {
+/// #     const LINK_OFFSET: usize = offset_of!(Node, link);
+/// # }
+/// const _: () = assert!(verify_layout::<Node, NodeAdapter>());
+/// ```
+///
+/// Lists evaluate this check when they are compiled, so an adapter that fails
+/// it can not be used with a list at all:
+/// ```compile_fail,E0080
+/// # use core::mem::offset_of;
+/// # use list::{Adapter, Link, UnsafeList};
+/// # #[repr(C)]
+/// # struct Node {
+/// #     value: u32,
+/// #     link: Link,
+/// # }
+/// struct NodeAdapter {}
+/// unsafe impl Adapter for NodeAdapter {
+///     const LINK_OFFSET: usize = core::mem::size_of::<Node>();
+/// }
+///
+/// let mut node = Node { value: 1, link: Link::new() };
+/// let mut list = UnsafeList::<Node, NodeAdapter>::new();
+/// unsafe { list.push_back_unchecked(&mut node) };
+/// ```
+pub const fn verify_layout<T, A: Adapter>() -> bool {
+    let Some(link_end) = A::LINK_OFFSET.checked_add(core::mem::size_of::<Link>()) else {
+        return false;
+    };
+
+    link_end <= core::mem::size_of::<T>() && A::LINK_OFFSET % core::mem::align_of::<Link>() == 0
 }
 
 impl<T, A: Adapter> UnsafeList<T, A> {
@@ -149,11 +626,43 @@ impl<T, A: Adapter> UnsafeList<T, A> {
         Self {
             head: None,
             tail: None,
+            len: 0,
+            max_len: 0,
             _phantom_type: PhantomData,
             _phantom_adapter: PhantomData,
         }
     }
 
+    /// Adopts an existing chain of links running from `head` to `tail`.
+    ///
+    /// The length of the list is counted by walking the chain, so this runs in
+    /// O(n) time.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure that:
+    /// * `head` and `tail` are either both `None` or both `Some`.
+    /// * Every link in the chain is the `Link` at `A::LINK_OFFSET` of a live
+    ///   `T`, reached through a pointer to the whole `T` that permits writes.
+    ///   A pointer taken from a reference to just the `link` field does not.
+    /// * `head` has no `prev` link, `tail` has no `next` link, and following
+    ///   `next` links from `head` reaches `tail`.
+    /// * Every `prev` link points to the link whose `next` link points back.
+    /// * No link in the chain is a member of another list.
+    pub unsafe fn from_raw(head: Option<NonNull<Link>>, tail: Option<NonNull<Link>>) -> Self {
+        let mut list = Self::new();
+        list.head = head;
+        list.tail = tail;
+
+        let mut cur = head;
+        while let Some(cur_ptr) = cur {
+            list.len += 1;
+            cur = (*cur_ptr.as_ptr()).get_next();
+        }
+        list.max_len = list.len;
+
+        list
+    }
+
     /// # Safety
     /// It is up to the caller to ensure exclusive access to the list and its
     /// members.
@@ -161,9 +670,87 @@ impl<T, A: Adapter> UnsafeList<T, A> {
         self.head.is_none()
     }
 
+    /// Returns true if the list has no members.
+    ///
+    /// Only the list header is read, so unlike `is_empty` this is safe and can
+    /// be evaluated in const contexts, e.g. on a `static` list before any
+    /// locks exist.
+    pub const fn is_empty_const(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Returns true if the list has exactly one member.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn is_singleton(&self) -> bool {
+        self.head.is_some() && self.head == self.tail
+    }
+
+    /// Returns the number of elements in the list.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the largest number of elements the list has held since it was
+    /// created or since the last call to `reset_stats`.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    /// Resets `max_len` to the current length of the list, starting a new
+    /// measurement interval.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn reset_stats(&mut self) {
+        self.max_len = self.len;
+    }
+
+    // Every element reaches the list through `get_link_ptr`, so referencing
+    // this there makes any use of a list with a bad adapter fail to compile.
+    // Derived adapters are already checked where they are implemented; this
+    // covers hand-written ones and generic structs.
+    const LAYOUT_CHECK: () = assert!(
+        verify_layout::<T, A>() && verify_adapter::<A>(),
+        "Adapter::LINK_OFFSET is out of bounds or misaligned"
+    );
+
+    // A pointer derived from `&T` only permits reads of the element, so it is
+    // only used to find or compare members.  Pointers stored in the list come
+    // from `get_link_ptr_mut` so that `&mut T` can be handed back out.
     unsafe fn get_link_ptr(element: &T) -> NonNull<Link> {
-        let element_ptr: NonNull<Link> = core::mem::transmute::<&T, NonNull<Link>>(element);
-        element_ptr.byte_add(A::LINK_OFFSET)
+        let () = Self::LAYOUT_CHECK;
+        NonNull::from(element).byte_add(A::LINK_OFFSET).cast()
+    }
+
+    unsafe fn get_link_ptr_mut(element: &mut T) -> NonNull<Link> {
+        let () = Self::LAYOUT_CHECK;
+        NonNull::from(element).byte_add(A::LINK_OFFSET).cast()
+    }
+
+    /// Returns the link of `element`, a member of the list, as stored by its
+    /// neighbor or the list head.  Unlike `get_link_ptr` the result carries the
+    /// permissions of the `&mut T` the member was pushed with, so it is safe to
+    /// store or to turn back into `&mut T`.
+    unsafe fn member_link_ptr(&self, element: &T) -> NonNull<Link> {
+        let element_ptr = Self::get_link_ptr(element);
+        let stored = match (*element_ptr.as_ptr()).get_prev() {
+            Some(prev_ptr) => (*prev_ptr.as_ptr()).get_next(),
+            None => self.head,
+        };
+        debug_assert!(stored == Some(element_ptr), "element is not in the list");
+        stored.unwrap_or(element_ptr)
     }
 
     unsafe fn get_element_ptr(link: NonNull<Link>) -> *const T {
@@ -181,7 +768,7 @@ impl<T, A: Adapter> UnsafeList<T, A> {
     /// members.
     /// It is up to the caller to ensure the element is not in a list
     pub unsafe fn push_front_unchecked(&mut self, element: &mut T) {
-        let element_ptr = Self::get_link_ptr(element);
+        let element_ptr = Self::get_link_ptr_mut(element);
 
         // Link up the added element.
         (*element_ptr.as_ptr()).set_next(self.head);
@@ -199,9 +786,189 @@ impl<T, A: Adapter> UnsafeList<T, A> {
 
         // Finally point `head` to the added element.
         self.head = Some(element_ptr);
+        self.len += 1;
+        self.max_len = self.max_len.max(self.len);
+    }
+
+    /// unchecked means we don't `assert!((*element_ptr.as_ptr()).is_unlinked());`
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure the element is not in a list
+    pub unsafe fn push_back_unchecked(&mut self, element: &mut T) {
+        let element_ptr = Self::get_link_ptr_mut(element);
+
+        // Link up the added element.
+        (*element_ptr.as_ptr()).set_next(None);
+        (*element_ptr.as_ptr()).set_prev(self.tail);
+
+        match self.tail {
+            // If `tail` was `None`, the list is empty and `head` should point
+            // to the added element.
+            None => self.head = Some(element_ptr),
+
+            // If `tail` is not `None`, point the previous `tail` to the added
+            // element.
+            Some(tail) => (*tail.as_ptr()).set_next(Some(element_ptr)),
+        }
+
+        // Finally point `tail` to the added element.
+        self.tail = Some(element_ptr);
+        self.len += 1;
+        self.max_len = self.max_len.max(self.len);
+    }
+
+    /// Pushes each element of `iter` onto the back of the list in iteration
+    /// order.  Equivalent to calling `push_back_unchecked` for each element.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure none of the elements are in a list
+    pub unsafe fn extend_back<'a, I: IntoIterator<Item = &'a mut T>>(&mut self, iter: I)
+    where
+        T: 'a,
+    {
+        for element in iter {
+            self.push_back_unchecked(element);
+        }
+    }
+
+    /// Pushes `element` onto the front of the list, panicking if it is
+    /// already linked into a list.
+    ///
+    /// The sole member of a list has no neighbors and can not be told apart
+    /// from an unlinked element.  Debug builds additionally walk the list and
+    /// panic if the element is already a member of it, which catches that
+    /// case for this list.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure the element is not in a list
+    pub unsafe fn push_front(&mut self, element: &mut T) {
+        debug_assert!(
+            !self.contains(element),
+            "element is already a member of this list"
+        );
+        assert!((*Self::get_link_ptr(element).as_ptr()).is_unlinked());
+        self.push_front_unchecked(element);
+    }
+
+    /// Pushes `element` onto the back of the list, panicking if it is
+    /// already linked into a list.
+    ///
+    /// The sole member of a list has no neighbors and can not be told apart
+    /// from an unlinked element.  Debug builds additionally walk the list and
+    /// panic if the element is already a member of it, which catches that
+    /// case for this list.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure the element is not in a list
+    pub unsafe fn push_back(&mut self, element: &mut T) {
+        debug_assert!(
+            !self.contains(element),
+            "element is already a member of this list"
+        );
+        assert!((*Self::get_link_ptr(element).as_ptr()).is_unlinked());
+        self.push_back_unchecked(element);
+    }
+
+    /// Inserts `element` into the list before the first member for which
+    /// `cmp(element, member)` returns `Ordering::Less`.  Inserting into a list
+    /// sorted by `cmp` keeps it sorted, with `element` placed after any members
+    /// that compare equal to it.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure the element is not in a list
+    pub unsafe fn insert_sorted<F: Fn(&T, &T) -> Ordering>(&mut self, element: &mut T, cmp: F) {
+        let mut cur = self.head;
+
+        while let Some(cur_ptr) = cur {
+            if cmp(element, &*Self::get_element_ptr(cur_ptr)) == Ordering::Less {
+                self.link_before(cur_ptr, Self::get_link_ptr_mut(element));
+                return;
+            }
+
+            cur = (*cur_ptr.as_ptr()).get_next();
+        }
+
+        // No member sorts after `element` so it belongs at the end of the list.
+        // This also covers the empty list.
+        self.push_back_unchecked(element);
+    }
+
+    /// Returns the first member for which `cmp(element, member)` returns
+    /// `Ordering::Equal`, leaving `element` unlinked.  If there is none,
+    /// inserts `element` as `insert_sorted` would and returns it.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure the element is not in a list
+    pub unsafe fn get_or_insert_sorted<'a, F: Fn(&T, &T) -> Ordering>(
+        &'a mut self,
+        element: &'a mut T,
+        cmp: F,
+    ) -> &'a mut T {
+        let mut cur = self.head;
+
+        while let Some(cur_ptr) = cur {
+            let member = Self::get_element_mut(cur_ptr);
+            match cmp(element, &*member) {
+                Ordering::Less => {
+                    let element_ptr = Self::get_link_ptr_mut(element);
+                    self.link_before(cur_ptr, element_ptr);
+                    return &mut *Self::get_element_mut(element_ptr);
+                }
+                Ordering::Equal => return &mut *member,
+                Ordering::Greater => {}
+            }
+
+            cur = (*cur_ptr.as_ptr()).get_next();
+        }
+
+        self.push_back_unchecked(element);
+        element
+    }
+
+    /// Links `element_ptr` into the list directly after `existing_ptr`.
+    unsafe fn link_after(&mut self, existing_ptr: NonNull<Link>, element_ptr: NonNull<Link>) {
+        let next = (*existing_ptr.as_ptr()).get_next();
+        Link::link_between(Some(existing_ptr), element_ptr, next);
+
+        // `existing` was the tail of the list.
+        if next.is_none() {
+            self.tail = Some(element_ptr);
+        }
+
+        self.len += 1;
+        self.max_len = self.max_len.max(self.len);
+    }
+
+    /// Links `element_ptr` into the list directly before `existing_ptr`.
+    unsafe fn link_before(&mut self, existing_ptr: NonNull<Link>, element_ptr: NonNull<Link>) {
+        let prev = (*existing_ptr.as_ptr()).get_prev();
+        Link::link_between(prev, element_ptr, Some(existing_ptr));
+
+        // `existing` was the head of the list.
+        if prev.is_none() {
+            self.head = Some(element_ptr);
+        }
+
+        self.len += 1;
+        self.max_len = self.max_len.max(self.len);
     }
 
-    /// unlinks element from the linked list.
+    /// unlinks element from the linked list and clears its links.
+    ///
+    /// Unlinking an element that is not linked panics in debug builds and does
+    /// nothing otherwise.
     ///
     /// # Safety
     /// It is up to the caller to ensure exclusive access to the list and its
@@ -210,6 +977,14 @@ impl<T, A: Adapter> UnsafeList<T, A> {
     pub unsafe fn unlink_element(&mut self, element: &T) {
         let element_ptr = Self::get_link_ptr(element);
 
+        // An element without neighbors is only in the list if it is the sole
+        // member.  Otherwise it has already been unlinked and touching `head`
+        // and `tail` would corrupt the list.
+        if (*element_ptr.as_ptr()).is_unlinked() && self.head != Some(element_ptr) {
+            debug_assert!(false, "unlinking an element that is not linked");
+            return;
+        }
+
         let prev = (*element_ptr.as_ptr()).get_prev();
         let next = (*element_ptr.as_ptr()).get_next();
 
@@ -228,65 +1003,2119 @@ impl<T, A: Adapter> UnsafeList<T, A> {
             // Element has elements after it in the list.
             Some(next_ptr) => (*next_ptr.as_ptr()).set_prev(prev),
         }
+
+        (*element_ptr.as_ptr()).mark_unlinked();
+        self.len -= 1;
     }
 
+    /// Accounts for a member that was removed with `Link::unlink_self`, which
+    /// can not update the list's length itself.  Call it once for every link
+    /// removed that way.
+    ///
     /// # Safety
     /// It is up to the caller to ensure exclusive access to the list and its
     /// members.
-    pub unsafe fn for_each<E, F: FnMut(&T) -> Result<(), E>>(
-        &self,
-        mut callback: F,
-    ) -> Result<(), E> {
-        let mut cur = self.head;
+    /// It is up to the caller to ensure a member was removed from the list
+    /// with `Link::unlink_self` and not yet accounted for
+    pub unsafe fn note_unlinked(&mut self) {
+        debug_assert!(self.len > 0, "no member left to account for");
+        self.len -= 1;
+    }
 
-        loop {
-            let Some(cur_ptr) = cur else {
-                break;
-            };
+    /// Removes the element at the head of the list and returns it with its
+    /// links cleared.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn pop_head<'a>(&mut self) -> Option<&'a mut T> {
+        let head = self.head?;
+        let element = &mut *Self::get_element_mut(head);
+        self.unlink_element(element);
+        Some(element)
+    }
 
-            let element = Self::get_element_ptr(cur_ptr);
-            callback(&*element)?;
+    /// Removes the element at the tail of the list and returns it with its
+    /// links cleared.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn pop_tail<'a>(&mut self) -> Option<&'a mut T> {
+        let tail = self.tail?;
+        let element = &mut *Self::get_element_mut(tail);
+        self.unlink_element(element);
+        Some(element)
+    }
 
-            cur = (*cur_ptr.as_ptr()).get_next();
-        }
+    /// Removes the element at the head of the list, returning it with its
+    /// links cleared, and pushes `element` onto the front in its place.  If
+    /// the list is empty, `element` is pushed and `None` is returned.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure the element is not in a list
+    pub unsafe fn replace_head<'a>(&mut self, element: &mut T) -> Option<&'a mut T> {
+        let head = self.pop_head();
+        self.push_front_unchecked(element);
+        head
+    }
 
-        Ok(())
+    /// Removes the element at the tail of the list, returning it with its
+    /// links cleared, and pushes `element` onto the back in its place.  If
+    /// the list is empty, `element` is pushed and `None` is returned.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure the element is not in a list
+    pub unsafe fn replace_tail<'a>(&mut self, element: &mut T) -> Option<&'a mut T> {
+        let tail = self.pop_tail();
+        self.push_back_unchecked(element);
+        tail
     }
 
-    /// Filter iterates over every element in the list calling `callback` on
-    /// each one.  If `callback` returns false, the element will be removed
-    /// from the list without modifying the element itself.  It is safe to
-    /// add the element to the another linked list within `callback` if it
-    /// returns false.
+    /// Returns the element at the head of the list without removing it.
     ///
     /// # Safety
     /// It is up to the caller to ensure exclusive access to the list and its
     /// members.
-    pub unsafe fn filter<F: FnMut(&mut T) -> bool>(&mut self, mut callback: F) {
-        let mut cur = self.head;
+    pub unsafe fn peek_head<'a>(&self) -> Option<&'a T> {
+        self.head.map(|head| &*Self::get_element_ptr(head))
+    }
 
-        loop {
-            let Some(cur_ptr) = cur else {
-                break;
-            };
+    /// Returns the element at the tail of the list without removing it.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn peek_tail<'a>(&self) -> Option<&'a T> {
+        self.tail.map(|tail| &*Self::get_element_ptr(tail))
+    }
 
-            let element = Self::get_element_mut(cur_ptr);
+    /// Returns an iterator over the elements of the list from head to tail.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members for the lifetime of the iterator.
+    pub unsafe fn iter(&self) -> Iter<'_, T, A> {
+        Iter {
+            next: self.head,
+            next_back: self.tail,
+            remaining: self.len,
+            _phantom_type: PhantomData,
+            _phantom_adapter: PhantomData,
+        }
+    }
 
-            // Cache the next element so that we don't rely on `element` staying
-            // coherent across calls to `callback`.
-            let next = (*cur_ptr.as_ptr()).get_next();
+    /// Returns an iterator over `start` and the elements after it, up to the
+    /// tail.  Creating the iterator walks from `start` to the tail to count
+    /// the elements it will yield.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members for the lifetime of the iterator.
+    /// It is up to the caller to ensure `start` is in the list
+    pub unsafe fn iter_from(&self, start: &T) -> Iter<'_, T, A> {
+        let start_ptr = Self::get_link_ptr(start);
+        let mut remaining = 0;
+        let mut cur = Some(start_ptr);
 
-            if !callback(&mut *element) {
-                self.unlink_element(&*element);
-            }
+        while let Some(cur_ptr) = cur {
+            remaining += 1;
+            cur = (*cur_ptr.as_ptr()).get_next();
+        }
 
-            cur = next;
+        Iter {
+            next: Some(start_ptr),
+            next_back: self.tail,
+            remaining,
+            _phantom_type: PhantomData,
+            _phantom_adapter: PhantomData,
         }
     }
-}
 
-impl<T, A: Adapter> Default for UnsafeList<T, A> {
-    fn default() -> Self {
-        Self::new()
+    /// Returns an iterator over the elements of the list from tail to head.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members for the lifetime of the iterator.
+    pub unsafe fn iter_rev(&self) -> IterRev<'_, T, A> {
+        IterRev {
+            next: self.tail,
+            _phantom_type: PhantomData,
+            _phantom_adapter: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over mutable references to the elements of the
+    /// list from head to tail.
+    ///
+    /// The next element is cached before the current one is yielded so
+    /// mutating the non-link fields of an element does not affect iteration.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members for the lifetime of the iterator.
+    /// It is up to the caller to ensure that no element is linked or unlinked
+    /// while iterating.
+    pub unsafe fn iter_mut(&mut self) -> IterMut<'_, T, A> {
+        IterMut {
+            next: self.head,
+            _phantom_type: PhantomData,
+            _phantom_adapter: PhantomData,
+        }
+    }
+
+    /// Returns a cursor positioned on the head of the list.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members for the lifetime of the cursor.
+    pub unsafe fn cursor_front_mut(&mut self) -> CursorMut<'_, T, A> {
+        CursorMut {
+            current: self.head,
+            list: self,
+        }
+    }
+
+    /// Returns a cursor positioned on `element`.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members for the lifetime of the cursor.
+    /// It is up to the caller to ensure the element is in the list
+    pub unsafe fn cursor_at(&mut self, element: &T) -> CursorMut<'_, T, A> {
+        CursorMut {
+            current: Some(self.member_link_ptr(element)),
+            list: self,
+        }
+    }
+
+    /// Calls `callback` on each element in order, stopping at and returning
+    /// the first error.
+    ///
+    /// `callback` may call other `&self` methods on the same list, e.g.
+    /// `position` or `len`, while the walk is in progress.  It can not modify
+    /// the list, which takes `&mut self` and is rejected at compile time.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn for_each<E, F: FnMut(&T) -> Result<(), E>>(
+        &self,
+        mut callback: F,
+    ) -> Result<(), E> {
+        // Reentrant reads are sound: the walk only holds shared references to
+        // the header and the current element, links are read through their
+        // `UnsafeCell`, and nothing reachable from `&self` writes to either.
+        let mut cur = self.head;
+
+        loop {
+            let Some(cur_ptr) = cur else {
+                break;
+            };
+
+            let element = Self::get_element_ptr(cur_ptr);
+            callback(&*element)?;
+
+            cur = (*cur_ptr.as_ptr()).get_next();
+        }
+
+        Ok(())
+    }
+
+    /// Calls `f` on each element in order, stopping after the first element
+    /// for which it returns false.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn for_each_while<F: FnMut(&T) -> bool>(&self, mut f: F) {
+        let mut cur = self.head;
+
+        while let Some(cur_ptr) = cur {
+            if !f(&*Self::get_element_ptr(cur_ptr)) {
+                break;
+            }
+
+            cur = (*cur_ptr.as_ptr()).get_next();
+        }
+    }
+
+    /// Calls `f` with each pair of adjacent elements, from the head to the
+    /// tail.  Lists with fewer than two elements make no calls.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn for_each_pair<F: FnMut(&T, &T)>(&self, mut f: F) {
+        let mut cur = self.head;
+
+        while let Some(cur_ptr) = cur {
+            let Some(next_ptr) = (*cur_ptr.as_ptr()).get_next() else {
+                break;
+            };
+
+            f(
+                &*Self::get_element_ptr(cur_ptr),
+                &*Self::get_element_ptr(next_ptr),
+            );
+
+            cur = Some(next_ptr);
+        }
+    }
+
+    /// Calls `callback` with a mutable reference to each element in order,
+    /// stopping at and returning the first error.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn for_each_mut<E, F: FnMut(&mut T) -> Result<(), E>>(
+        &mut self,
+        mut callback: F,
+    ) -> Result<(), E> {
+        let mut cur = self.head;
+
+        while let Some(cur_ptr) = cur {
+            let element = Self::get_element_mut(cur_ptr);
+
+            // Cache the next element so that we don't rely on `element` staying
+            // coherent across calls to `callback`.
+            cur = (*cur_ptr.as_ptr()).get_next();
+
+            callback(&mut *element)?;
+        }
+
+        Ok(())
+    }
+
+    /// Calls `callback` with the list and a mutable reference to each element
+    /// in order.  `callback` may unlink the element it is given, e.g. to move
+    /// it to another list, and the walk continues with the element that
+    /// followed it.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure `callback` unlinks no element other
+    /// than the one it is given
+    pub unsafe fn for_each_removable<F: FnMut(&mut Self, &mut T)>(&mut self, mut callback: F) {
+        let mut cur = self.head;
+
+        while let Some(cur_ptr) = cur {
+            let element = Self::get_element_mut(cur_ptr);
+
+            // Cache the next element before `callback` can unlink `element`
+            // and clear its links.
+            cur = (*cur_ptr.as_ptr()).get_next();
+
+            callback(self, &mut *element);
+        }
+    }
+
+    /// Filter iterates over every element in the list calling `callback` on
+    /// each one.  If `callback` returns false, the element will be removed
+    /// from the list without modifying the element itself.  It is safe to
+    /// add the element to the another linked list within `callback` if it
+    /// returns false.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn filter<F: FnMut(&mut T) -> bool>(&mut self, callback: F) {
+        self.retain(callback);
+    }
+
+    /// Behaves like `filter` and returns the number of elements removed from
+    /// the list.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn retain<F: FnMut(&mut T) -> bool>(&mut self, callback: F) -> usize {
+        let mut removed = 0;
+        self.retain_with(callback, |_| removed += 1);
+        removed
+    }
+
+    /// Behaves like `filter` and passes each removed element, with its links
+    /// already cleared, to `on_remove`.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn retain_with<F: FnMut(&mut T) -> bool, G: FnMut(&mut T)>(
+        &mut self,
+        keep: F,
+        on_remove: G,
+    ) {
+        self.retain_from_link(self.head, keep, on_remove);
+    }
+
+    /// Behaves like `filter` but starts at `start` instead of the head.
+    /// Elements before `start` are neither passed to `callback` nor removed,
+    /// which bounds the work done by a single call.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure `start` is in the list
+    pub unsafe fn filter_from<F: FnMut(&mut T) -> bool>(&mut self, start: &T, callback: F) {
+        self.retain_from_link(Some(self.member_link_ptr(start)), callback, |_| {});
+    }
+
+    unsafe fn retain_from_link<F: FnMut(&mut T) -> bool, G: FnMut(&mut T)>(
+        &mut self,
+        start: Option<NonNull<Link>>,
+        mut keep: F,
+        mut on_remove: G,
+    ) {
+        let mut cur = start;
+
+        loop {
+            let Some(cur_ptr) = cur else {
+                break;
+            };
+
+            let element = Self::get_element_mut(cur_ptr);
+
+            // Cache the next element so that we don't rely on `element` staying
+            // coherent across calls to `keep`.
+            let next = (*cur_ptr.as_ptr()).get_next();
+
+            if !keep(&mut *element) {
+                self.unlink_element(&*element);
+                on_remove(&mut *element);
+            }
+
+            cur = next;
+        }
+    }
+
+    /// Moves every element of `other` onto the back of this list in O(1),
+    /// leaving `other` empty.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to both lists and
+    /// their members.
+    pub unsafe fn append(&mut self, other: &mut UnsafeList<T, A>) {
+        let Some(other_head) = other.head else {
+            return;
+        };
+
+        match self.tail {
+            // If `self` is empty, it takes over `other`'s head.
+            None => self.head = Some(other_head),
+
+            // Otherwise join `other`'s chain onto the end of `self`.
+            Some(tail) => {
+                (*tail.as_ptr()).set_next(Some(other_head));
+                (*other_head.as_ptr()).set_prev(Some(tail));
+            }
+        }
+
+        self.tail = other.tail;
+        self.len += other.len;
+        self.max_len = self.max_len.max(self.len);
+
+        other.head = None;
+        other.tail = None;
+        other.len = 0;
+    }
+
+    /// Moves every element of `other` onto the front of this list in O(1),
+    /// leaving `other` empty.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to both lists and
+    /// their members.
+    pub unsafe fn prepend(&mut self, other: &mut UnsafeList<T, A>) {
+        let Some(other_tail) = other.tail else {
+            return;
+        };
+
+        match self.head {
+            // If `self` is empty, it takes over `other`'s tail.
+            None => self.tail = Some(other_tail),
+
+            // Otherwise join the start of `self` onto `other`'s chain.
+            Some(head) => {
+                (*head.as_ptr()).set_prev(Some(other_tail));
+                (*other_tail.as_ptr()).set_next(Some(head));
+            }
+        }
+
+        self.head = other.head;
+        self.len += other.len;
+        self.max_len = self.max_len.max(self.len);
+
+        other.head = None;
+        other.tail = None;
+        other.len = 0;
+    }
+
+    /// Moves every element of `other`, in order, directly before `existing`
+    /// in O(1), leaving `other` empty.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to both lists and
+    /// their members.
+    /// It is up to the caller to ensure `existing` is in this list
+    pub unsafe fn splice_before(&mut self, existing: &T, other: &mut UnsafeList<T, A>) {
+        let (Some(other_head), Some(other_tail)) = (other.head, other.tail) else {
+            return;
+        };
+        let existing_ptr = self.member_link_ptr(existing);
+
+        let prev = (*existing_ptr.as_ptr()).get_prev();
+        (*existing_ptr.as_ptr()).set_prev(Some(other_tail));
+        (*other_tail.as_ptr()).set_next(Some(existing_ptr));
+        (*other_head.as_ptr()).set_prev(prev);
+        self.set_next_or_head(prev, Some(other_head));
+
+        self.len += other.len;
+        self.max_len = self.max_len.max(self.len);
+
+        other.head = None;
+        other.tail = None;
+        other.len = 0;
+    }
+
+    /// Returns true if `element` is a member of this list.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn contains(&self, element: &T) -> bool {
+        let element_ptr = Self::get_link_ptr(element);
+
+        // An element without neighbors is either not in a list or is the only
+        // member of one, which can be checked without walking.
+        if (*element_ptr.as_ptr()).is_unlinked() {
+            return self.head == Some(element_ptr);
+        }
+
+        let mut cur = self.head;
+
+        while let Some(cur_ptr) = cur {
+            if cur_ptr == element_ptr {
+                return true;
+            }
+
+            cur = (*cur_ptr.as_ptr()).get_next();
+        }
+
+        false
+    }
+
+    /// Returns true if the list's links are consistent.  See `validate`.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn is_valid(&self) -> bool {
+        self.check_invariants().is_ok()
+    }
+
+    /// Walks the list forward and backward and panics if its links are
+    /// inconsistent.  This is O(n) and intended for tests and debug builds.
+    ///
+    /// Checked invariants:
+    /// * `head` and `tail` are either both `None` or both `Some`.
+    /// * The head has no `prev` and the tail has no `next`.
+    /// * Following `next` from the head ends at the tail and following `prev`
+    ///   from the tail ends at the head.
+    /// * Every node's `next` points back to it through `prev`.
+    /// * Both walks visit exactly `len()` nodes.
+    ///
+    /// A node whose `prev` no longer points back, here because it was pushed
+    /// onto a second list without being unlinked first, fails the check:
+    /// ```should_panic
+    /// # use core::mem::offset_of;
+    /// # use list::{Adapter, Link, UnsafeList};
+    /// # #[repr(C)]
+    /// # struct Node {
+    /// #     value: u32,
+    /// #     link: Link,
+    /// # }
+    /// # struct NodeAdapter {}
+    /// # unsafe impl Adapter for NodeAdapter {
+    /// #     const LINK_OFFSET: usize = offset_of!(Node, link);
+    /// # }
+    /// let mut node1 = Node { value: 1, link: Link::new() };
+    /// let mut node2 = Node { value: 2, link: Link::new() };
+    /// let mut node3 = Node { value: 3, link: Link::new() };
+    ///
+    /// let mut list = UnsafeList::<Node, NodeAdapter>::new();
+    /// unsafe { list.push_back_unchecked(&mut node1) };
+    /// unsafe { list.push_back_unchecked(&mut node2) };
+    /// unsafe { list.validate() };
+    ///
+    /// let mut other = UnsafeList::<Node, NodeAdapter>::new();
+    /// unsafe { other.push_back_unchecked(&mut node3) };
+    /// unsafe { other.push_back_unchecked(&mut node2) };
+    /// // Panics with "next node's prev does not point back".
+    /// unsafe { list.validate() };
+    /// ```
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn validate(&self) {
+        if let Err(message) = self.check_invariants() {
+            panic!("list invariant violated: {}", message);
+        }
+    }
+
+    unsafe fn check_invariants(&self) -> Result<(), &'static str> {
+        let (Some(head), Some(tail)) = (self.head, self.tail) else {
+            if self.head.is_some() || self.tail.is_some() {
+                return Err("only one of head and tail is set");
+            }
+            if self.len != 0 {
+                return Err("empty list has non-zero len");
+            }
+            return Ok(());
+        };
+
+        if (*head.as_ptr()).get_prev().is_some() {
+            return Err("head has a prev link");
+        }
+        if (*tail.as_ptr()).get_next().is_some() {
+            return Err("tail has a next link");
+        }
+
+        // Walks are bounded by `len` so that a cycle is reported rather than
+        // looping forever.
+        let mut count = 1;
+        let mut cur_ptr = head;
+        while let Some(next_ptr) = (*cur_ptr.as_ptr()).get_next() {
+            if (*next_ptr.as_ptr()).get_prev() != Some(cur_ptr) {
+                return Err("next node's prev does not point back");
+            }
+            count += 1;
+            if count > self.len {
+                return Err("forward walk is longer than len");
+            }
+            cur_ptr = next_ptr;
+        }
+        if cur_ptr != tail {
+            return Err("forward walk does not end at tail");
+        }
+        if count != self.len {
+            return Err("forward walk is shorter than len");
+        }
+
+        let mut count = 1;
+        let mut cur_ptr = tail;
+        while let Some(prev_ptr) = (*cur_ptr.as_ptr()).get_prev() {
+            count += 1;
+            if count > self.len {
+                return Err("backward walk is longer than len");
+            }
+            cur_ptr = prev_ptr;
+        }
+        if cur_ptr != head {
+            return Err("backward walk does not end at head");
+        }
+        if count != self.len {
+            return Err("backward walk is shorter than len");
+        }
+
+        Ok(())
+    }
+
+    /// Unlinks every element, clearing their links so they read as unlinked,
+    /// and leaves the list empty.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn clear(&mut self) {
+        let mut cur = self.head;
+
+        while let Some(cur_ptr) = cur {
+            cur = (*cur_ptr.as_ptr()).get_next();
+
+            (*cur_ptr.as_ptr()).mark_unlinked();
+        }
+
+        self.head = None;
+        self.tail = None;
+        self.len = 0;
+    }
+
+    /// Returns an iterator which pops elements from the head of the list,
+    /// yielding them with their links cleared.  Dropping the iterator before
+    /// it is exhausted leaves the remaining elements in the list.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members for the lifetime of the iterator.
+    pub unsafe fn drain(&mut self) -> Drain<'_, T, A> {
+        Drain { list: self }
+    }
+
+    /// Inserts `element` directly after `existing`.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure `existing` is in the list and the
+    /// element is not in a list
+    pub unsafe fn insert_after(&mut self, existing: &T, element: &mut T) {
+        self.link_after(
+            self.member_link_ptr(existing),
+            Self::get_link_ptr_mut(element),
+        );
+    }
+
+    /// Inserts `element` directly before `existing`.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure `existing` is in the list and the
+    /// element is not in a list
+    pub unsafe fn insert_before(&mut self, existing: &T, element: &mut T) {
+        self.link_before(
+            self.member_link_ptr(existing),
+            Self::get_link_ptr_mut(element),
+        );
+    }
+
+    /// Moves `element` to the head of the list.  Does nothing if it is
+    /// already the head.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure the element is in the list
+    pub unsafe fn move_to_front(&mut self, element: &mut T) {
+        if self.head == Some(Self::get_link_ptr(element)) {
+            return;
+        }
+
+        self.unlink_element(element);
+        self.push_front_unchecked(element);
+    }
+
+    /// Moves `element` to the tail of the list.  Does nothing if it is
+    /// already the tail.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure the element is in the list
+    pub unsafe fn move_to_back(&mut self, element: &mut T) {
+        if self.tail == Some(Self::get_link_ptr(element)) {
+            return;
+        }
+
+        self.unlink_element(element);
+        self.push_back_unchecked(element);
+    }
+
+    /// Reverses the order of the elements in the list.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn reverse(&mut self) {
+        let mut cur = self.head;
+
+        while let Some(cur_ptr) = cur {
+            let next = (*cur_ptr.as_ptr()).get_next();
+            let prev = (*cur_ptr.as_ptr()).get_prev();
+            (*cur_ptr.as_ptr()).set_next(prev);
+            (*cur_ptr.as_ptr()).set_prev(next);
+
+            cur = next;
+        }
+
+        core::mem::swap(&mut self.head, &mut self.tail);
+    }
+
+    /// Reverses the order of the elements from `first` to `last`, inclusive,
+    /// leaving the rest of the list in place.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure both elements are in the list and
+    /// `first` is not after `last`
+    pub unsafe fn reverse_between(&mut self, first: &T, last: &T) {
+        let first_ptr = self.member_link_ptr(first);
+        let last_ptr = self.member_link_ptr(last);
+        let before = (*first_ptr.as_ptr()).get_prev();
+        let after = (*last_ptr.as_ptr()).get_next();
+
+        let mut cur = Some(first_ptr);
+
+        while let Some(cur_ptr) = cur {
+            let next = (*cur_ptr.as_ptr()).get_next();
+            let prev = (*cur_ptr.as_ptr()).get_prev();
+            (*cur_ptr.as_ptr()).set_next(prev);
+            (*cur_ptr.as_ptr()).set_prev(next);
+
+            if cur_ptr == last_ptr {
+                break;
+            }
+            cur = next;
+        }
+
+        // The ends of the range still point outside of it the wrong way round.
+        (*first_ptr.as_ptr()).set_next(after);
+        (*last_ptr.as_ptr()).set_prev(before);
+        self.set_next_or_head(before, Some(last_ptr));
+        self.set_prev_or_tail(after, Some(first_ptr));
+    }
+
+    /// Splits the list after `element`.  `self` keeps every element up to and
+    /// including `element` and the rest are returned in a new list.
+    ///
+    /// This walks the returned elements to keep both lengths up to date.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure the element is in the list
+    pub unsafe fn split_after(&mut self, element: &T) -> UnsafeList<T, A> {
+        let element_ptr = self.member_link_ptr(element);
+        let mut other = UnsafeList::new();
+
+        let Some(other_head) = (*element_ptr.as_ptr()).get_next() else {
+            // `element` is the tail so there is nothing to split off.
+            return other;
+        };
+
+        let mut cur = Some(other_head);
+        while let Some(cur_ptr) = cur {
+            other.len += 1;
+            cur = (*cur_ptr.as_ptr()).get_next();
+        }
+
+        (*element_ptr.as_ptr()).set_next(None);
+        (*other_head.as_ptr()).set_prev(None);
+
+        other.head = Some(other_head);
+        other.tail = self.tail;
+        other.max_len = other.len;
+        self.tail = Some(element_ptr);
+        self.len -= other.len;
+
+        other
+    }
+
+    /// Splits the list at `index`.  `self` keeps the first `index` elements
+    /// and the rest are returned in a new list.
+    ///
+    /// `split_off(0)` moves every element into the returned list, and an
+    /// `index` at or past the length returns an empty list.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn split_off(&mut self, index: usize) -> UnsafeList<T, A> {
+        if index == 0 {
+            return self.take();
+        }
+
+        match self.nth_link(index - 1) {
+            Some(last_ptr) => self.split_after(&*Self::get_element_ptr(last_ptr)),
+            None => UnsafeList::new(),
+        }
+    }
+
+    /// Returns a pointer to the element at the head of the list.
+    ///
+    /// The pointer is only valid while the element stays linked into the list.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn front_ptr(&self) -> Option<*const T> {
+        self.head.map(|head| Self::get_element_ptr(head))
+    }
+
+    /// Returns a pointer to the element at the tail of the list.
+    ///
+    /// The pointer is only valid while the element stays linked into the list.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn back_ptr(&self) -> Option<*const T> {
+        self.tail.map(|tail| Self::get_element_ptr(tail))
+    }
+
+    /// Exchanges the positions of `a` and `b` in the list.  Swapping an
+    /// element with itself does nothing.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure both elements are in the list
+    pub unsafe fn swap_nodes(&mut self, a: &mut T, b: &mut T) {
+        let mut a_ptr = Self::get_link_ptr_mut(a);
+        let mut b_ptr = Self::get_link_ptr_mut(b);
+
+        if a_ptr == b_ptr {
+            return;
+        }
+
+        // Normalize adjacent elements so that `a` comes directly before `b`.
+        if (*b_ptr.as_ptr()).get_next() == Some(a_ptr) {
+            core::mem::swap(&mut a_ptr, &mut b_ptr);
+        }
+
+        let a_prev = (*a_ptr.as_ptr()).get_prev();
+        let a_next = (*a_ptr.as_ptr()).get_next();
+        let b_prev = (*b_ptr.as_ptr()).get_prev();
+        let b_next = (*b_ptr.as_ptr()).get_next();
+
+        if a_next == Some(b_ptr) {
+            // Adjacent: `a_prev, a, b, b_next` becomes `a_prev, b, a, b_next`.
+            self.set_next_or_head(a_prev, Some(b_ptr));
+            (*b_ptr.as_ptr()).set_prev(a_prev);
+            (*b_ptr.as_ptr()).set_next(Some(a_ptr));
+            (*a_ptr.as_ptr()).set_prev(Some(b_ptr));
+            (*a_ptr.as_ptr()).set_next(b_next);
+            self.set_prev_or_tail(b_next, Some(a_ptr));
+            return;
+        }
+
+        // Non-adjacent: each element takes over the other's neighbors.
+        (*a_ptr.as_ptr()).set_prev(b_prev);
+        (*a_ptr.as_ptr()).set_next(b_next);
+        (*b_ptr.as_ptr()).set_prev(a_prev);
+        (*b_ptr.as_ptr()).set_next(a_next);
+
+        self.set_next_or_head(a_prev, Some(b_ptr));
+        self.set_prev_or_tail(a_next, Some(b_ptr));
+        self.set_next_or_head(b_prev, Some(a_ptr));
+        self.set_prev_or_tail(b_next, Some(a_ptr));
+    }
+
+    /// Points `prev`'s `next` link at `value`, or `head` if `prev` is `None`.
+    unsafe fn set_next_or_head(
+        &mut self,
+        prev: Option<NonNull<Link>>,
+        value: Option<NonNull<Link>>,
+    ) {
+        match prev {
+            None => self.head = value,
+            Some(prev_ptr) => (*prev_ptr.as_ptr()).set_next(value),
+        }
+    }
+
+    /// Points `next`'s `prev` link at `value`, or `tail` if `next` is `None`.
+    unsafe fn set_prev_or_tail(
+        &mut self,
+        next: Option<NonNull<Link>>,
+        value: Option<NonNull<Link>>,
+    ) {
+        match next {
+            None => self.tail = value,
+            Some(next_ptr) => (*next_ptr.as_ptr()).set_prev(value),
+        }
+    }
+
+    /// Sorts the list with a stable, bottom-up merge sort.
+    ///
+    /// Only links are rewritten: elements are neither moved nor copied and no
+    /// memory is allocated.  Runs in O(n log n) time.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn sort_by<F: Fn(&T, &T) -> Ordering>(&mut self, cmp: F) {
+        let mut head = self.head;
+        if head.is_none() {
+            return;
+        }
+
+        // Each pass merges adjacent runs of `run_len` elements into runs of
+        // `2 * run_len` elements following only `next` links.  `prev` links
+        // are repaired once the sort is finished.
+        let mut run_len = 1;
+        loop {
+            let mut left = head;
+            let mut merged_tail: Option<NonNull<Link>> = None;
+            let mut merges = 0;
+            head = None;
+
+            while left.is_some() {
+                merges += 1;
+
+                // Find the start of the right run.
+                let mut right = left;
+                let mut left_len = 0;
+                while left_len < run_len {
+                    let Some(right_ptr) = right else {
+                        break;
+                    };
+                    left_len += 1;
+                    right = (*right_ptr.as_ptr()).get_next();
+                }
+                let mut right_len = run_len;
+
+                // Merge the two runs.  Taking from the left run on ties keeps
+                // the sort stable.
+                loop {
+                    let left_head = left.filter(|_| left_len > 0);
+                    let right_head = right.filter(|_| right_len > 0);
+                    let take_right = match (left_head, right_head) {
+                        (None, None) => break,
+                        (Some(_), None) => false,
+                        (None, Some(_)) => true,
+                        (Some(left_ptr), Some(right_ptr)) => {
+                            cmp(
+                                &*Self::get_element_ptr(left_ptr),
+                                &*Self::get_element_ptr(right_ptr),
+                            ) == Ordering::Greater
+                        }
+                    };
+
+                    let (taken, taken_len) = if take_right {
+                        (&mut right, &mut right_len)
+                    } else {
+                        (&mut left, &mut left_len)
+                    };
+                    let Some(taken_ptr) = *taken else {
+                        break;
+                    };
+                    *taken = (*taken_ptr.as_ptr()).get_next();
+                    *taken_len -= 1;
+
+                    match merged_tail {
+                        None => head = Some(taken_ptr),
+                        Some(tail_ptr) => (*tail_ptr.as_ptr()).set_next(Some(taken_ptr)),
+                    }
+                    merged_tail = Some(taken_ptr);
+                }
+
+                left = right;
+            }
+
+            if let Some(tail_ptr) = merged_tail {
+                (*tail_ptr.as_ptr()).set_next(None);
+            }
+
+            if merges <= 1 {
+                break;
+            }
+            run_len *= 2;
+        }
+
+        // Repair `prev` links along with `head` and `tail`.
+        self.head = head;
+        let mut prev = None;
+        let mut cur = head;
+        while let Some(cur_ptr) = cur {
+            (*cur_ptr.as_ptr()).set_prev(prev);
+            prev = cur;
+            cur = (*cur_ptr.as_ptr()).get_next();
+        }
+        self.tail = prev;
+    }
+
+    /// Moves the first `n` elements, in order, to the back of the list.  `n`
+    /// wraps modulo the length of the list.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn rotate_left(&mut self, n: usize) {
+        let (Some(head), Some(tail)) = (self.head, self.tail) else {
+            return;
+        };
+
+        let n = n % self.len;
+        if n == 0 {
+            return;
+        }
+
+        // Find the last of the elements being moved.
+        let mut last_moved = head;
+        for _ in 1..n {
+            let Some(next_ptr) = (*last_moved.as_ptr()).get_next() else {
+                break;
+            };
+            last_moved = next_ptr;
+        }
+        let Some(new_head) = (*last_moved.as_ptr()).get_next() else {
+            return;
+        };
+
+        // Close the list into a ring and then break it before `new_head`.
+        (*tail.as_ptr()).set_next(Some(head));
+        (*head.as_ptr()).set_prev(Some(tail));
+        (*last_moved.as_ptr()).set_next(None);
+        (*new_head.as_ptr()).set_prev(None);
+
+        self.head = Some(new_head);
+        self.tail = Some(last_moved);
+    }
+
+    /// Moves the last `n` elements, in order, to the front of the list.  `n`
+    /// wraps modulo the length of the list.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn rotate_right(&mut self, n: usize) {
+        if self.len == 0 {
+            return;
+        }
+
+        self.rotate_left(self.len - n % self.len);
+    }
+
+    /// Returns the first element, starting from the head, for which `pred`
+    /// returns true.  The element stays linked into the list.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn find_mut<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> Option<&mut T> {
+        let mut cur = self.head;
+
+        loop {
+            let cur_ptr = cur?;
+            let element = Self::get_element_mut(cur_ptr);
+            if pred(&*element) {
+                return Some(&mut *element);
+            }
+
+            cur = (*cur_ptr.as_ptr()).get_next();
+        }
+    }
+
+    /// Returns the first element, starting from the tail, for which `pred`
+    /// returns true.  The element stays linked into the list.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn rfind_mut<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> Option<&mut T> {
+        let mut cur = self.tail;
+
+        loop {
+            let cur_ptr = cur?;
+            let element = Self::get_element_mut(cur_ptr);
+            if pred(&*element) {
+                return Some(&mut *element);
+            }
+
+            cur = (*cur_ptr.as_ptr()).get_prev();
+        }
+    }
+
+    /// Returns the first element, starting from the head, for which `pred`
+    /// returns true.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn first_where<F: FnMut(&T) -> bool>(&self, mut pred: F) -> Option<&T> {
+        let mut cur = self.head;
+
+        loop {
+            let cur_ptr = cur?;
+            let element = &*Self::get_element_ptr(cur_ptr);
+            if pred(element) {
+                return Some(element);
+            }
+
+            cur = (*cur_ptr.as_ptr()).get_next();
+        }
+    }
+
+    /// Returns the first element, starting from the tail, for which `pred`
+    /// returns true.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn last_where<F: FnMut(&T) -> bool>(&self, mut pred: F) -> Option<&T> {
+        let mut cur = self.tail;
+
+        loop {
+            let cur_ptr = cur?;
+            let element = &*Self::get_element_ptr(cur_ptr);
+            if pred(element) {
+                return Some(element);
+            }
+
+            cur = (*cur_ptr.as_ptr()).get_prev();
+        }
+    }
+
+    /// Returns the zero-based index of `element` in the list, or `None` if it
+    /// is not a member.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn position(&self, element: &T) -> Option<usize> {
+        let element_ptr = Self::get_link_ptr(element);
+        let mut index = 0;
+        let mut cur = self.head;
+
+        loop {
+            let cur_ptr = cur?;
+            if cur_ptr == element_ptr {
+                return Some(index);
+            }
+
+            index += 1;
+            cur = (*cur_ptr.as_ptr()).get_next();
+        }
+    }
+
+    /// Returns the zero-based index of `element` in the list, or `None` if it
+    /// is not a member, together with the number of members counted by the
+    /// same walk.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn index_and_len(&self, element: &T) -> (Option<usize>, usize) {
+        let element_ptr = Self::get_link_ptr(element);
+        let mut index = None;
+        let mut len = 0;
+        let mut cur = self.head;
+
+        while let Some(cur_ptr) = cur {
+            if cur_ptr == element_ptr {
+                index = Some(len);
+            }
+
+            len += 1;
+            cur = (*cur_ptr.as_ptr()).get_next();
+        }
+
+        (index, len)
+    }
+
+    /// Merges the members of `other` into the list, leaving `other` empty.
+    ///
+    /// If both lists are sorted by `cmp` the result is sorted by `cmp`, with
+    /// members of `self` placed before members of `other` that compare equal.
+    /// Only links are rewritten and the merge runs in O(n + m) time.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to both lists and
+    /// their members.
+    pub unsafe fn merge<F: Fn(&T, &T) -> Ordering>(
+        &mut self,
+        other: &mut UnsafeList<T, A>,
+        cmp: F,
+    ) {
+        let mut other_cur = other.head;
+        other.head = None;
+        other.tail = None;
+        other.len = 0;
+
+        let mut cur = self.head;
+        while let Some(other_ptr) = other_cur {
+            other_cur = (*other_ptr.as_ptr()).get_next();
+            let other_element = &*Self::get_element_ptr(other_ptr);
+
+            // Skip past members of `self` that sort before or equal to the
+            // element from `other`.
+            while let Some(cur_ptr) = cur {
+                if cmp(&*Self::get_element_ptr(cur_ptr), other_element) == Ordering::Greater {
+                    break;
+                }
+                cur = (*cur_ptr.as_ptr()).get_next();
+            }
+
+            match cur {
+                // Every remaining member of `self` sorts before the element.
+                None => self.push_back_unchecked(&mut *Self::get_element_mut(other_ptr)),
+                Some(cur_ptr) => self.link_before(cur_ptr, other_ptr),
+            }
+        }
+    }
+
+    /// Returns the element `index` steps from the head or `None` if the list
+    /// has `index` or fewer members.  Runs in O(index) time.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn nth(&self, index: usize) -> Option<&T> {
+        self.nth_link(index)
+            .map(|link| &*Self::get_element_ptr(link))
+    }
+
+    /// Returns the element `index` steps from the head or `None` if the list
+    /// has `index` or fewer members.  Runs in O(index) time.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn nth_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.nth_link(index)
+            .map(|link| &mut *Self::get_element_mut(link))
+    }
+
+    /// Links `element` into the list so that it ends up `index` steps from
+    /// the head, or returns it untouched if `index` is greater than the length
+    /// of the list.  Runs in O(index) time.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure the element is not in a list
+    pub unsafe fn insert_at<'a>(
+        &mut self,
+        index: usize,
+        element: &'a mut T,
+    ) -> Result<(), &'a mut T> {
+        if index > self.len {
+            return Err(element);
+        }
+
+        match self.nth_link(index) {
+            // `index == len`, including the empty list.
+            None => self.push_back_unchecked(element),
+            Some(cur_ptr) => self.link_before(cur_ptr, Self::get_link_ptr_mut(element)),
+        }
+
+        Ok(())
+    }
+
+    /// Removes the element `index` steps from the head and returns it with its
+    /// links cleared, or returns `None` if the list has `index` or fewer
+    /// members.  Runs in O(index) time.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn remove_at(&mut self, index: usize) -> Option<&mut T> {
+        let element = &mut *Self::get_element_mut(self.nth_link(index)?);
+        self.unlink_element(element);
+        Some(element)
+    }
+
+    unsafe fn nth_link(&self, index: usize) -> Option<NonNull<Link>> {
+        let mut cur = self.head;
+        for _ in 0..index {
+            cur = (*cur?.as_ptr()).get_next();
+        }
+        cur
+    }
+
+    /// Moves every element into a new list in O(1), leaving this list empty.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn take(&mut self) -> UnsafeList<T, A> {
+        let mut other = UnsafeList::new();
+        other.append(self);
+        other
+    }
+
+    /// Moves every element into a new list sorted by `cmp`, leaving this list
+    /// empty.  Equivalent to `take` followed by `sort_by`, so only links are
+    /// rewritten.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn drain_sorted_into<F: Fn(&T, &T) -> Ordering>(
+        &mut self,
+        cmp: F,
+    ) -> UnsafeList<T, A> {
+        let mut other = self.take();
+        other.sort_by(cmp);
+        other
+    }
+
+    /// Returns the number of elements for which `pred` returns true.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn count_where<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize {
+        let mut count = 0;
+        let mut cur = self.head;
+
+        while let Some(cur_ptr) = cur {
+            if pred(&*Self::get_element_ptr(cur_ptr)) {
+                count += 1;
+            }
+            cur = (*cur_ptr.as_ptr()).get_next();
+        }
+
+        count
+    }
+
+    /// Returns true if the list has at least `k` members.  Walks at most `k`
+    /// links instead of relying on the length, so it stays correct for chains
+    /// adopted without their length and never walks past what it needs.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn has_at_least(&self, k: usize) -> bool {
+        k == 0 || self.nth_link(k - 1).is_some()
+    }
+
+    /// Puts `new` in `old`'s place in the list and clears `old`'s links.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure `old` is in the list and `new` is not
+    /// in a list
+    pub unsafe fn replace(&mut self, old: &T, new: &mut T) {
+        let old_ptr = Self::get_link_ptr(old);
+        let new_ptr = Self::get_link_ptr_mut(new);
+
+        let prev = (*old_ptr.as_ptr()).get_prev();
+        let next = (*old_ptr.as_ptr()).get_next();
+
+        (*new_ptr.as_ptr()).set_prev(prev);
+        (*new_ptr.as_ptr()).set_next(next);
+        self.set_next_or_head(prev, Some(new_ptr));
+        self.set_prev_or_tail(next, Some(new_ptr));
+
+        (*old_ptr.as_ptr()).mark_unlinked();
+    }
+
+    /// Returns true if every pair of adjacent elements is in order according
+    /// to `cmp`, i.e. `cmp` never returns `Ordering::Greater` for an element
+    /// and the one after it.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn is_sorted_by<F: Fn(&T, &T) -> Ordering>(&self, cmp: F) -> bool {
+        let Some(mut prev_ptr) = self.head else {
+            return true;
+        };
+
+        while let Some(cur_ptr) = (*prev_ptr.as_ptr()).get_next() {
+            if cmp(
+                &*Self::get_element_ptr(prev_ptr),
+                &*Self::get_element_ptr(cur_ptr),
+            ) == Ordering::Greater
+            {
+                return false;
+            }
+            prev_ptr = cur_ptr;
+        }
+
+        true
+    }
+
+    /// Moves every element for which `pred` returns true onto the back of
+    /// `dest`, keeping their relative order.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to both lists and
+    /// their members.
+    pub unsafe fn move_matching_to<F: FnMut(&T) -> bool>(
+        &mut self,
+        dest: &mut UnsafeList<T, A>,
+        mut pred: F,
+    ) {
+        let mut cur = self.head;
+
+        while let Some(cur_ptr) = cur {
+            let element = Self::get_element_mut(cur_ptr);
+
+            // Cache the next element before `element` is moved to `dest`.
+            cur = (*cur_ptr.as_ptr()).get_next();
+
+            if pred(&*element) {
+                self.unlink_element(&*element);
+                dest.push_back_unchecked(&mut *element);
+            }
+        }
+    }
+
+    /// Exchanges the contents of this list and `other` in O(1), along with
+    /// their lengths and statistics.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to both lists and
+    /// their members.
+    pub unsafe fn swap_lists(&mut self, other: &mut UnsafeList<T, A>) {
+        // No links point at a list header, so swapping the headers moves
+        // every field consistently.
+        core::mem::swap(self, other);
+    }
+
+    /// Returns a second header for the same chain of elements.
+    ///
+    /// This aliases the list: both headers point at the same members and
+    /// neither sees changes made through the other.  `UnsafeList` does not
+    /// implement `Clone` for this reason.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure neither the list nor its members are
+    /// modified, through either header, while the snapshot is in use
+    pub unsafe fn snapshot(&self) -> Self {
+        Self {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            max_len: self.max_len,
+            _phantom_type: PhantomData,
+            _phantom_adapter: PhantomData,
+        }
+    }
+
+    /// Removes every element for which `same(previous, element)` returns true,
+    /// where `previous` is the nearest kept element before it.  Removed
+    /// elements have their links cleared.  Returns the number of elements
+    /// removed.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn dedup_by<F: FnMut(&T, &T) -> bool>(&mut self, mut same: F) -> usize {
+        let Some(mut kept_ptr) = self.head else {
+            return 0;
+        };
+        let mut removed = 0;
+
+        while let Some(cur_ptr) = (*kept_ptr.as_ptr()).get_next() {
+            let element = &*Self::get_element_ptr(cur_ptr);
+            if same(&*Self::get_element_ptr(kept_ptr), element) {
+                self.unlink_element(element);
+                removed += 1;
+            } else {
+                kept_ptr = cur_ptr;
+            }
+        }
+
+        removed
+    }
+
+    /// Keeps the elements for which `pred` returns true and moves the rest,
+    /// in order, into the returned list.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn partition<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> UnsafeList<T, A> {
+        let mut rejected = UnsafeList::new();
+        self.move_matching_to(&mut rejected, |element| !pred(element));
+        rejected
+    }
+
+    /// Writes the list as `[elem0, elem1, ...]`, formatting each element with
+    /// `per_elem`.
+    ///
+    /// This lets lists of elements that are not `Debug` be dumped for
+    /// diagnostics, e.g. from the `Debug` impl of a wrapper type.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn debug_fmt<F: FnMut(&T, &mut core::fmt::Formatter) -> core::fmt::Result>(
+        &self,
+        f: &mut core::fmt::Formatter,
+        mut per_elem: F,
+    ) -> core::fmt::Result {
+        f.write_str("[")?;
+
+        let mut cur = self.head;
+        while let Some(cur_ptr) = cur {
+            if cur != self.head {
+                f.write_str(", ")?;
+            }
+            per_elem(&*Self::get_element_ptr(cur_ptr), f)?;
+            cur = (*cur_ptr.as_ptr()).get_next();
+        }
+
+        f.write_str("]")
+    }
+
+    /// Rotates the list so that `element` becomes the head.  The elements
+    /// before it are moved, in order, to the back of the list.  Runs in O(1)
+    /// time.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure the element is in the list
+    pub unsafe fn rotate_to(&mut self, element: &T) {
+        let element_ptr = self.member_link_ptr(element);
+        let (Some(head), Some(tail)) = (self.head, self.tail) else {
+            return;
+        };
+        let Some(new_tail) = (*element_ptr.as_ptr()).get_prev() else {
+            // Element is already at the head of the list.
+            return;
+        };
+
+        // Close the list into a ring and then break it before `element`.
+        (*tail.as_ptr()).set_next(Some(head));
+        (*head.as_ptr()).set_prev(Some(tail));
+        (*new_tail.as_ptr()).set_next(None);
+        (*element_ptr.as_ptr()).set_prev(None);
+
+        self.head = Some(element_ptr);
+        self.tail = Some(new_tail);
+    }
+
+    /// Rotates the list so that the first element for which `pred` returns
+    /// true becomes the head, as if the head were repeatedly moved to the back
+    /// until it matched.  Returns false and leaves the list unchanged if no
+    /// element matches.  `pred` is called at most once per element.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn rotate_until<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> bool {
+        let mut cur = self.head;
+
+        loop {
+            let Some(cur_ptr) = cur else {
+                return false;
+            };
+
+            let element = &*Self::get_element_ptr(cur_ptr);
+            if pred(element) {
+                self.rotate_to(element);
+                return true;
+            }
+
+            cur = (*cur_ptr.as_ptr()).get_next();
+        }
+    }
+
+    /// Returns the element after `element`, or `None` if it is the tail.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure the element is in the list
+    pub unsafe fn next_of(&self, element: &T) -> Option<&T> {
+        let next = (*Self::get_link_ptr(element).as_ptr()).get_next();
+        next.map(|next_ptr| &*Self::get_element_ptr(next_ptr))
+    }
+
+    /// Returns the element before `element`, or `None` if it is the head.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure the element is in the list
+    pub unsafe fn prev_of(&self, element: &T) -> Option<&T> {
+        let prev = (*Self::get_link_ptr(element).as_ptr()).get_prev();
+        prev.map(|prev_ptr| &*Self::get_element_ptr(prev_ptr))
+    }
+
+    /// Appends each list in `lists`, in order, into a new list and returns
+    /// it, leaving every list in `lists` empty.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the lists and
+    /// their members.
+    pub unsafe fn concat_all(lists: &mut [UnsafeList<T, A>]) -> UnsafeList<T, A> {
+        let mut combined = UnsafeList::new();
+        for list in lists {
+            combined.append(list);
+        }
+        combined
+    }
+
+    /// Returns an iterator over the elements of each list in `lists`, in
+    /// order, as if they were concatenated.  The lists are not modified.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the lists and
+    /// their members for the lifetime of the iterator.
+    pub unsafe fn chain<'a>(lists: &'a [&'a UnsafeList<T, A>]) -> ChainIter<'a, T, A> {
+        ChainIter {
+            lists: lists.iter(),
+            current: None,
+        }
+    }
+
+    /// Returns true if `element`'s link has a neighbor, i.e. it is in a list
+    /// with other members.
+    ///
+    /// The sole member of a list has no neighbors and reads as unlinked.  Use
+    /// `contains` to check membership of a specific list.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the element's
+    /// list, if any, and its members.
+    pub unsafe fn is_element_linked(element: &T) -> bool {
+        (*Self::get_link_ptr(element).as_ptr()).is_linked()
+    }
+
+    /// Threads an accumulator through the elements from head to tail,
+    /// starting with `init`, and returns the final value.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn fold<B, F: FnMut(B, &T) -> B>(&self, init: B, mut f: F) -> B {
+        let mut acc = init;
+        let mut cur = self.head;
+
+        while let Some(cur_ptr) = cur {
+            acc = f(acc, &*Self::get_element_ptr(cur_ptr));
+            cur = (*cur_ptr.as_ptr()).get_next();
+        }
+
+        acc
+    }
+
+    /// Removes and returns the element at the head of the list if `pred`
+    /// returns true for it.  Otherwise the list is left unchanged.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn pop_head_if<'a, F: FnOnce(&T) -> bool>(&mut self, pred: F) -> Option<&'a mut T> {
+        if !pred(self.peek_head()?) {
+            return None;
+        }
+
+        self.pop_head()
+    }
+
+    /// Removes the element at the head of the list, clearing its links, and
+    /// returns the result of calling `f` on it.  Returns `None` without
+    /// calling `f` if the list is empty.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn pop_head_map<R, F: FnOnce(&mut T) -> R>(&mut self, f: F) -> Option<R> {
+        self.pop_head().map(f)
+    }
+
+    /// Pops up to `N` elements from the head of the list, clearing their
+    /// links, and returns them in order along with the number popped.  Slots
+    /// past the count are `None` and any remaining elements stay linked.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn drain_into<'a, const N: usize>(&mut self) -> ([Option<&'a mut T>; N], usize) {
+        let mut elements = [const { None }; N];
+        let mut count = 0;
+
+        for slot in elements.iter_mut() {
+            let Some(element) = self.pop_head() else {
+                break;
+            };
+            *slot = Some(element);
+            count += 1;
+        }
+
+        (elements, count)
+    }
+
+    /// Unlinks `element` from the list and returns true if the list is now
+    /// empty.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure the element is in the list
+    pub unsafe fn unlink_returning_empty(&mut self, element: &T) -> bool {
+        self.unlink_element(element);
+        self.head.is_none()
+    }
+
+    /// Returns the number of elements from `first` to `last`, inclusive.
+    ///
+    /// Panics in debug builds if the tail is reached without finding `last`.
+    /// In release builds the elements from `first` to the tail are counted.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure both elements are in the list and
+    /// `first` is not after `last`
+    pub unsafe fn count_between(&self, first: &T, last: &T) -> usize {
+        let last_ptr = Self::get_link_ptr(last);
+        let mut count = 0;
+        let mut cur = Some(Self::get_link_ptr(first));
+
+        while let Some(cur_ptr) = cur {
+            count += 1;
+            if cur_ptr == last_ptr {
+                break;
+            }
+
+            cur = (*cur_ptr.as_ptr()).get_next();
+            debug_assert!(cur.is_some(), "`last` is not at or after `first`");
+        }
+
+        count
+    }
+}
+
+// Only the list header is logged since elements may not implement `Format`.
+#[cfg(feature = "defmt")]
+impl<T, A: Adapter> defmt::Format for UnsafeList<T, A> {
+    fn format(&self, f: defmt::Formatter) {
+        let addr = |link: Option<NonNull<Link>>| link.map_or(0, |ptr| ptr.as_ptr() as usize);
+        defmt::write!(
+            f,
+            "UnsafeList {{ len: {=usize}, head: {=usize:#x}, tail: {=usize:#x} }}",
+            self.len,
+            addr(self.head),
+            addr(self.tail)
+        );
+    }
+}
+
+impl<T, A: Adapter> Default for UnsafeList<T, A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The operations shared by list containers, so that generic code written
+/// against a `Vec`-like interface can be handed any of them.
+///
+/// Intrusive lists own no storage, so the capacity management methods have
+/// nothing to do.  They are provided so such code compiles unchanged.
+pub trait ListLike<T> {
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    unsafe fn len(&self) -> usize;
+
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    unsafe fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure the element is not in a list
+    unsafe fn push_back(&mut self, element: &mut T);
+
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    unsafe fn pop_head<'a>(&mut self) -> Option<&'a mut T>;
+
+    /// Returns true if the list holds no spare capacity, which is always the
+    /// case for an intrusive list.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    unsafe fn is_compact(&self) -> bool {
+        true
+    }
+
+    /// Does nothing.  An intrusive list never needs to allocate to grow.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    unsafe fn reserve(&mut self, _additional: usize) {}
+
+    /// Does nothing.  An intrusive list has no spare capacity to release.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    unsafe fn shrink_to_fit(&mut self) {}
+}
+
+impl<T, A: Adapter> ListLike<T> for UnsafeList<T, A> {
+    unsafe fn len(&self) -> usize {
+        UnsafeList::len(self)
+    }
+
+    unsafe fn push_back(&mut self, element: &mut T) {
+        UnsafeList::push_back(self, element)
+    }
+
+    unsafe fn pop_head<'a>(&mut self) -> Option<&'a mut T> {
+        UnsafeList::pop_head(self)
+    }
+}
+
+// `next` and `next_back` are the next elements to be yielded from either end.
+// Once they land on the same element it is the last one left and yielding it
+// from either end clears both.  `remaining` counts the elements not yet
+// yielded.
+pub struct Iter<'a, T, A: Adapter> {
+    next: Option<NonNull<Link>>,
+    next_back: Option<NonNull<Link>>,
+    remaining: usize,
+    _phantom_type: PhantomData<&'a T>,
+    _phantom_adapter: PhantomData<A>,
+}
+
+impl<'a, T, A: Adapter> Iterator for Iter<'a, T, A> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cur_ptr = self.next?;
+
+        // Safety: The caller of `UnsafeList::iter()` guarantees exclusive
+        // access to the list and its members for the lifetime of the iterator.
+        unsafe {
+            if self.next_back == Some(cur_ptr) {
+                self.next = None;
+                self.next_back = None;
+            } else {
+                self.next = (*cur_ptr.as_ptr()).get_next();
+            }
+            self.remaining -= 1;
+            Some(&*UnsafeList::<T, A>::get_element_ptr(cur_ptr))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T, A: Adapter> DoubleEndedIterator for Iter<'_, T, A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let cur_ptr = self.next_back?;
+
+        // Safety: The caller of `UnsafeList::iter()` guarantees exclusive
+        // access to the list and its members for the lifetime of the iterator.
+        unsafe {
+            if self.next == Some(cur_ptr) {
+                self.next = None;
+                self.next_back = None;
+            } else {
+                self.next_back = (*cur_ptr.as_ptr()).get_prev();
+            }
+            self.remaining -= 1;
+            Some(&*UnsafeList::<T, A>::get_element_ptr(cur_ptr))
+        }
+    }
+}
+
+impl<T, A: Adapter> ExactSizeIterator for Iter<'_, T, A> {}
+
+pub struct IterRev<'a, T, A: Adapter> {
+    next: Option<NonNull<Link>>,
+    _phantom_type: PhantomData<&'a T>,
+    _phantom_adapter: PhantomData<A>,
+}
+
+impl<'a, T, A: Adapter> Iterator for IterRev<'a, T, A> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cur_ptr = self.next?;
+
+        // Safety: The caller of `UnsafeList::iter_rev()` guarantees exclusive
+        // access to the list and its members for the lifetime of the iterator.
+        unsafe {
+            self.next = (*cur_ptr.as_ptr()).get_prev();
+            Some(&*UnsafeList::<T, A>::get_element_ptr(cur_ptr))
+        }
+    }
+}
+
+pub struct ChainIter<'a, T, A: Adapter> {
+    lists: core::slice::Iter<'a, &'a UnsafeList<T, A>>,
+    current: Option<Iter<'a, T, A>>,
+}
+
+impl<'a, T, A: Adapter> Iterator for ChainIter<'a, T, A> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(element) = self.current.as_mut().and_then(Iterator::next) {
+                return Some(element);
+            }
+
+            // Safety: The caller of `UnsafeList::chain()` guarantees exclusive
+            // access to every list and its members for the lifetime of the
+            // iterator.
+            self.current = Some(unsafe { self.lists.next()?.iter() });
+        }
+    }
+}
+
+pub struct IterMut<'a, T, A: Adapter> {
+    next: Option<NonNull<Link>>,
+    _phantom_type: PhantomData<&'a mut T>,
+    _phantom_adapter: PhantomData<A>,
+}
+
+impl<'a, T, A: Adapter> Iterator for IterMut<'a, T, A> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cur_ptr = self.next?;
+
+        // Safety: The caller of `UnsafeList::iter_mut()` guarantees exclusive
+        // access to the list and its members for the lifetime of the iterator.
+        unsafe {
+            // Cache the next element before handing out a mutable reference to
+            // the current one.
+            self.next = (*cur_ptr.as_ptr()).get_next();
+            Some(&mut *UnsafeList::<T, A>::get_element_mut(cur_ptr))
+        }
+    }
+}
+
+pub struct Drain<'a, T, A: Adapter> {
+    list: &'a mut UnsafeList<T, A>,
+}
+
+impl<'a, T, A: Adapter> Iterator for Drain<'a, T, A> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Safety: The caller of `UnsafeList::drain()` guarantees exclusive
+        // access to the list and its members for the lifetime of the iterator.
+        unsafe { self.list.pop_head() }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+}
+
+impl<T, A: Adapter> ExactSizeIterator for Drain<'_, T, A> {}
+
+/// A cursor over an `UnsafeList` which can insert and remove elements at its
+/// position.
+///
+/// In addition to pointing at an element, the cursor may be positioned on a
+/// "ghost" non-element that sits between the tail and the head of the list.
+/// Moving past either end of the list lands on the ghost and moving again
+/// wraps around to the other end.
+pub struct CursorMut<'a, T, A: Adapter> {
+    list: &'a mut UnsafeList<T, A>,
+    current: Option<NonNull<Link>>,
+}
+
+impl<'a, T, A: Adapter> CursorMut<'a, T, A> {
+    /// Moves the cursor to the next element, or to the ghost if it was on
+    /// the tail.  Moving next from the ghost moves to the head.
+    pub fn move_next(&mut self) {
+        self.current = match self.current {
+            // Safety: The caller of `UnsafeList::cursor_front_mut()` guarantees
+            // exclusive access to the list and its members.
+            Some(cur_ptr) => unsafe { (*cur_ptr.as_ptr()).get_next() },
+            None => self.list.head,
+        };
+    }
+
+    /// Moves the cursor to the previous element, or to the ghost if it was on
+    /// the head.  Moving previous from the ghost moves to the tail.
+    pub fn move_prev(&mut self) {
+        self.current = match self.current {
+            // Safety: The caller of `UnsafeList::cursor_front_mut()` guarantees
+            // exclusive access to the list and its members.
+            Some(cur_ptr) => unsafe { (*cur_ptr.as_ptr()).get_prev() },
+            None => self.list.tail,
+        };
+    }
+
+    /// Returns the element the cursor is positioned on or `None` if the
+    /// cursor is on the ghost.
+    pub fn current(&mut self) -> Option<&mut T> {
+        // Safety: The caller of `UnsafeList::cursor_front_mut()` guarantees
+        // exclusive access to the list and its members.
+        self.current
+            .map(|cur_ptr| unsafe { &mut *UnsafeList::<T, A>::get_element_mut(cur_ptr) })
+    }
+
+    /// Inserts `element` after the cursor's position.  If the cursor is on the
+    /// ghost, `element` becomes the new head.  The cursor does not move.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure the element is not in a list
+    pub unsafe fn insert_after(&mut self, element: &mut T) {
+        match self.current {
+            Some(cur_ptr) => self
+                .list
+                .link_after(cur_ptr, UnsafeList::<T, A>::get_link_ptr_mut(element)),
+            None => self.list.push_front_unchecked(element),
+        }
+    }
+
+    /// Inserts `element` before the cursor's position.  If the cursor is on
+    /// the ghost, `element` becomes the new tail.  The cursor does not move.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure the element is not in a list
+    pub unsafe fn insert_before(&mut self, element: &mut T) {
+        match self.current {
+            Some(cur_ptr) => self
+                .list
+                .link_before(cur_ptr, UnsafeList::<T, A>::get_link_ptr_mut(element)),
+            None => self.list.push_back_unchecked(element),
+        }
+    }
+
+    /// Moves every element of `other`, in order, after the cursor's position
+    /// in O(1), leaving `other` empty.  If the cursor is on the ghost, the
+    /// elements are placed at the front of the list.  The cursor does not
+    /// move.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to `other` and its
+    /// members.
+    pub unsafe fn splice_after(&mut self, other: &mut UnsafeList<T, A>) {
+        let Some(cur_ptr) = self.current else {
+            self.list.prepend(other);
+            return;
+        };
+        let (Some(other_head), Some(other_tail)) = (other.head, other.tail) else {
+            return;
+        };
+
+        let next = (*cur_ptr.as_ptr()).get_next();
+        (*cur_ptr.as_ptr()).set_next(Some(other_head));
+        (*other_head.as_ptr()).set_prev(Some(cur_ptr));
+        (*other_tail.as_ptr()).set_next(next);
+        self.list.set_prev_or_tail(next, Some(other_tail));
+
+        self.list.len += other.len;
+        self.list.max_len = self.list.max_len.max(self.list.len);
+
+        other.head = None;
+        other.tail = None;
+        other.len = 0;
+    }
+
+    /// Removes the element the cursor is positioned on and returns it with its
+    /// links cleared.  The cursor moves to the following element, or to the
+    /// ghost if the removed element was the tail.  Returns `None` and does
+    /// nothing if the cursor is on the ghost.
+    pub fn remove_current(&mut self) -> Option<&'a mut T> {
+        let cur_ptr = self.current?;
+
+        // Safety: The caller of `UnsafeList::cursor_front_mut()` guarantees
+        // exclusive access to the list and its members.
+        unsafe {
+            let element = &mut *UnsafeList::<T, A>::get_element_mut(cur_ptr);
+            self.current = (*cur_ptr.as_ptr()).get_next();
+            self.list.unlink_element(element);
+            Some(element)
+        }
     }
 }
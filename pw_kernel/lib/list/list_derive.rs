@@ -0,0 +1,127 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, punctuated::Punctuated, Data, DeriveInput, Error, Index, Meta, Token,
+};
+
+// The link field is marked with `#[list_link]` rather than `#[link]` because
+// `link` is a built-in attribute and derive helpers can not shadow it.
+#[proc_macro_derive(Adapter, attributes(list_link))]
+pub fn derive_adapter(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match adapter_impl(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn adapter_impl(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            ident,
+            "`Adapter` can only be derived for structs",
+        ));
+    };
+
+    if !is_repr_c(input)? {
+        return Err(Error::new_spanned(
+            ident,
+            "`Adapter` can only be derived for `#[repr(C)]` structs",
+        ));
+    }
+
+    let mut link_member = None;
+    for (index, field) in data.fields.iter().enumerate() {
+        if !field
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident("list_link"))
+        {
+            continue;
+        }
+
+        if link_member.is_some() {
+            return Err(Error::new_spanned(
+                field,
+                "only one field may be marked `#[list_link]`",
+            ));
+        }
+
+        let member = match &field.ident {
+            Some(field_ident) => quote! { #field_ident },
+            None => {
+                let index = Index::from(index);
+                quote! { #index }
+            }
+        };
+        link_member = Some((member, &field.ty));
+    }
+
+    let Some((link_member, link_ty)) = link_member else {
+        return Err(Error::new_spanned(
+            ident,
+            "`Adapter` requires a `Link` field marked `#[list_link]`",
+        ));
+    };
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // A free `const` item can not name generic parameters, so generic structs
+    // are only checked by the lists they are used with.
+    let layout_check = input.generics.params.is_empty().then(|| {
+        quote! {
+            const _: () = ::core::assert!(
+                ::list::verify_adapter::<#ident>(),
+                "`#[list_link]` field is out of bounds or misaligned"
+            );
+        }
+    });
+
+    Ok(quote! {
+        #layout_check
+
+        unsafe impl #impl_generics ::list::Adapter for #ident #ty_generics #where_clause {
+            const ADAPTER_TYPE_SIZE: usize = ::core::mem::size_of::<#ident #ty_generics>();
+            const LINK_OFFSET: usize = {
+                // Fails to compile if the marked field is not a `Link`.  The
+                // field's type is checked rather than a reference to the
+                // field, which would also accept fields that deref to a
+                // `Link`, e.g. a `&'static Link`.
+                let _: fn(#link_ty) -> ::list::Link = |link| link;
+                ::core::mem::offset_of!(#ident #ty_generics, #link_member)
+            };
+        }
+    })
+}
+
+fn is_repr_c(input: &DeriveInput) -> syn::Result<bool> {
+    for attr in &input.attrs {
+        if !attr.path().is_ident("repr") {
+            continue;
+        }
+
+        let reprs = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        if reprs.iter().any(|repr| repr.path().is_ident("C")) {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
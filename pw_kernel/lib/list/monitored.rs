@@ -0,0 +1,186 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+//! An [`UnsafeList`] whose length can be read without holding its lock.
+//!
+//! [`MonitoredList`] is meant to live next to, not inside, the lock that
+//! serializes its writers.  Every mutation still requires exclusive access,
+//! but [`MonitoredList::len_relaxed`] can be called at any time from any
+//! thread, e.g. by a monitor reporting queue depths.
+//!
+//! The length is mirrored into an `AtomicUsize` with a plain `Relaxed` store
+//! after each mutation.  Writers are already serialized, so no
+//! read-modify-write is needed and targets without compare-and-swap (e.g.
+//! ARMv6-M) are supported.  The value read is approximate: it may lag behind
+//! a mutation in progress, but it is always a length the list has had.
+//!
+//! A monitor can read the length while a writer holding the lock mutates the
+//! list:
+//! ```
+//! # use core::mem::offset_of;
+//! # use core::sync::atomic::{AtomicBool, Ordering};
+//! # use std::sync::Mutex;
+//! # use list::{monitored::MonitoredList, Adapter, Link};
+//! # #[repr(C)]
+//! # struct Node {
+//! #     value: usize,
+//! #     link: Link,
+//! # }
+//! # struct NodeAdapter {}
+//! # unsafe impl Adapter for NodeAdapter {
+//! #     const LINK_OFFSET: usize = offset_of!(Node, link);
+//! # }
+//! const NODES: usize = 50;
+//! static LIST: MonitoredList<Node, NodeAdapter> = MonitoredList::new();
+//! static LOCK: Mutex<()> = Mutex::new(());
+//! static DONE: AtomicBool = AtomicBool::new(false);
+//!
+//! let writer = std::thread::spawn(|| {
+//!     for value in 0..NODES {
+//!         let node = Box::leak(Box::new(Node { value, link: Link::new() }));
+//!         let _guard = LOCK.lock().unwrap();
+//!         unsafe { LIST.push_back(node) };
+//!     }
+//!     for value in 0..NODES {
+//!         let guard = LOCK.lock().unwrap();
+//!         let node = unsafe { LIST.pop_head() }.unwrap();
+//!         drop(guard);
+//!         assert_eq!(node.value, value);
+//!         drop(unsafe { Box::from_raw(node) });
+//!     }
+//!     DONE.store(true, Ordering::Release);
+//! });
+//!
+//! // The monitor never takes the lock.
+//! let monitor = std::thread::spawn(|| {
+//!     while !DONE.load(Ordering::Acquire) {
+//!         assert!(LIST.len_relaxed() <= NODES);
+//!         std::thread::yield_now();
+//!     }
+//! });
+//!
+//! writer.join().unwrap();
+//! monitor.join().unwrap();
+//! assert_eq!(LIST.len_relaxed(), 0);
+//! ```
+
+use core::cell::UnsafeCell;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::{Adapter, UnsafeList};
+
+pub struct MonitoredList<T, A: Adapter> {
+    list: UnsafeCell<UnsafeList<T, A>>,
+    len: AtomicUsize,
+}
+
+// Safety: `len` is only accessed atomically.  Every access to `list` goes
+// through an unsafe method whose caller guarantees exclusive access, exactly
+// as if the list were behind a lock, so sharing the header only requires that
+// elements can be handed between threads.
+unsafe impl<T: Send, A: Adapter> Sync for MonitoredList<T, A> {}
+
+impl<T, A: Adapter> MonitoredList<T, A> {
+    pub const fn new() -> Self {
+        Self {
+            list: UnsafeCell::new(UnsafeList::new()),
+            len: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the number of elements in the list without synchronizing with
+    /// writers.  Only suitable for monitoring.
+    pub fn len_relaxed(&self) -> usize {
+        self.len.load(Ordering::Relaxed)
+    }
+
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn is_empty(&self) -> bool {
+        (*self.list.get()).is_empty()
+    }
+
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn len(&self) -> usize {
+        (*self.list.get()).len()
+    }
+
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure the element is not in a list
+    pub unsafe fn push_front(&self, element: &mut T) {
+        let list = &mut *self.list.get();
+        list.push_front_unchecked(element);
+        self.len.store(list.len(), Ordering::Relaxed);
+    }
+
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure the element is not in a list
+    pub unsafe fn push_back(&self, element: &mut T) {
+        let list = &mut *self.list.get();
+        list.push_back_unchecked(element);
+        self.len.store(list.len(), Ordering::Relaxed);
+    }
+
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    /// It is up to the caller to ensure the element is in the list
+    pub unsafe fn unlink_element(&self, element: &T) {
+        let list = &mut *self.list.get();
+        list.unlink_element(element);
+        self.len.store(list.len(), Ordering::Relaxed);
+    }
+
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn pop_head<'a>(&self) -> Option<&'a mut T> {
+        let list = &mut *self.list.get();
+        let element = list.pop_head();
+        self.len.store(list.len(), Ordering::Relaxed);
+        element
+    }
+
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members.
+    pub unsafe fn pop_tail<'a>(&self) -> Option<&'a mut T> {
+        let list = &mut *self.list.get();
+        let element = list.pop_tail();
+        self.len.store(list.len(), Ordering::Relaxed);
+        element
+    }
+
+    /// Returns the underlying list for read-only operations.
+    ///
+    /// # Safety
+    /// It is up to the caller to ensure exclusive access to the list and its
+    /// members for the lifetime of the returned reference.
+    pub unsafe fn as_list(&self) -> &UnsafeList<T, A> {
+        &*self.list.get()
+    }
+}
+
+impl<T, A: Adapter> Default for MonitoredList<T, A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,304 @@
+// Copyright 2025 The Pigweed Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not
+// use this file except in compliance with the License. You may obtain a copy of
+// the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+//! A safe wrapper around [`UnsafeList`].
+//!
+//! [`UnsafeList`] leaves exclusive access to the list and its members up to
+//! the caller.  [`LinkedList`] enforces both with the borrow checker:
+//! * Every mutating method takes `&mut self`, so the list header can not be
+//!   aliased while it is being modified.
+//! * Elements are pushed as `&'a mut T` and the list holds that borrow until
+//!   the element is popped or the list is dropped.  While an element is in a
+//!   list it can not be read, written, moved, or dropped by anyone else, and
+//!   it can not be pushed onto a second `LinkedList`.
+//!
+//! Holding the element's only mutable borrow is what makes the list the
+//! logical owner of the element.  Popping an element hands that borrow back to
+//! the caller.  Dropping the list pops every remaining element so their links
+//! read as unlinked once the borrow ends.
+//!
+//! Accessing an element while it is in the list does not compile:
+//! ```compile_fail,E0506
+//! # use core::mem::offset_of;
+//! # use list::{safe::LinkedList, Adapter, Link};
+//! # #[repr(C)]
+//! # struct Node {
+//! #     value: u32,
+//! #     link: Link,
+//! # }
+//! # struct NodeAdapter {}
+//! # unsafe impl Adapter for NodeAdapter {
+//! #     const LINK_OFFSET: usize = offset_of!(Node, link);
+//! # }
+//! let mut node = Node { value: 1, link: Link::new() };
+//! let mut list = LinkedList::<Node, NodeAdapter>::new();
+//! list.push_back(&mut node);
+//! node.value = 2;
+//! list.pop_head();
+//! ```
+//!
+//! Neither does moving an element while it is in the list:
+//! ```compile_fail,E0505
+//! # use core::mem::offset_of;
+//! # use list::{safe::LinkedList, Adapter, Link};
+//! # #[repr(C)]
+//! # struct Node {
+//! #     value: u32,
+//! #     link: Link,
+//! # }
+//! # struct NodeAdapter {}
+//! # unsafe impl Adapter for NodeAdapter {
+//! #     const LINK_OFFSET: usize = offset_of!(Node, link);
+//! # }
+//! let mut node = Node { value: 1, link: Link::new() };
+//! let mut list = LinkedList::<Node, NodeAdapter>::new();
+//! list.push_back(&mut node);
+//! let moved = node;
+//! list.pop_head();
+//! ```
+//!
+//! Or letting an element go out of scope before the list:
+//! ```compile_fail,E0597
+//! # use core::mem::offset_of;
+//! # use list::{safe::LinkedList, Adapter, Link};
+//! # #[repr(C)]
+//! # struct Node {
+//! #     value: u32,
+//! #     link: Link,
+//! # }
+//! # struct NodeAdapter {}
+//! # unsafe impl Adapter for NodeAdapter {
+//! #     const LINK_OFFSET: usize = offset_of!(Node, link);
+//! # }
+//! let mut list = LinkedList::<Node, NodeAdapter>::new();
+//! {
+//!     let mut node = Node { value: 1, link: Link::new() };
+//!     list.push_back(&mut node);
+//! }
+//! ```
+//!
+//! Or pushing the same element onto two lists:
+//! ```compile_fail,E0499
+//! # use core::mem::offset_of;
+//! # use list::{safe::LinkedList, Adapter, Link};
+//! # #[repr(C)]
+//! # struct Node {
+//! #     value: u32,
+//! #     link: Link,
+//! # }
+//! # struct NodeAdapter {}
+//! # unsafe impl Adapter for NodeAdapter {
+//! #     const LINK_OFFSET: usize = offset_of!(Node, link);
+//! # }
+//! let mut node = Node { value: 1, link: Link::new() };
+//! let mut list_a = LinkedList::<Node, NodeAdapter>::new();
+//! let mut list_b = LinkedList::<Node, NodeAdapter>::new();
+//! list_a.push_back(&mut node);
+//! list_b.push_back(&mut node);
+//! list_a.pop_head();
+//! ```
+//!
+//! # Pinned elements
+//!
+//! [`PinnedList`] takes and returns elements as `Pin<&'a mut T>`.  Because
+//! `Link` is `!Unpin`, no element type containing one can be moved out of a
+//! `Pin` without `unsafe`, so elements pinned with [`core::pin::pin!`] can be
+//! linked and stay put both while they are in the list and after they are
+//! popped.
+//!
+//! Moving a pinned element does not compile:
+//! ```compile_fail,E0507
+//! # use core::mem::offset_of;
+//! # use core::pin::pin;
+//! # use list::{safe::PinnedList, Adapter, Link};
+//! # #[repr(C)]
+//! # struct Node {
+//! #     value: u32,
+//! #     link: Link,
+//! # }
+//! # struct NodeAdapter {}
+//! # unsafe impl Adapter for NodeAdapter {
+//! #     const LINK_OFFSET: usize = offset_of!(Node, link);
+//! # }
+//! let mut node = pin!(Node { value: 1, link: Link::new() });
+//! let mut list = PinnedList::<Node, NodeAdapter>::new();
+//! list.push_back(node.as_mut());
+//! let moved = *node;
+//! ```
+//!
+//! Neither does swapping it out after it has been popped:
+//! ```compile_fail,E0277
+//! # use core::mem::offset_of;
+//! # use core::pin::{pin, Pin};
+//! # use list::{safe::PinnedList, Adapter, Link};
+//! # #[repr(C)]
+//! # struct Node {
+//! #     value: u32,
+//! #     link: Link,
+//! # }
+//! # struct NodeAdapter {}
+//! # unsafe impl Adapter for NodeAdapter {
+//! #     const LINK_OFFSET: usize = offset_of!(Node, link);
+//! # }
+//! let node = pin!(Node { value: 1, link: Link::new() });
+//! let mut other = Node { value: 2, link: Link::new() };
+//! let mut list = PinnedList::<Node, NodeAdapter>::new();
+//! list.push_back(node);
+//! if let Some(popped) = list.pop_head() {
+//!     core::mem::swap(Pin::get_mut(popped), &mut other);
+//! }
+//! ```
+
+use core::marker::PhantomData;
+use core::pin::Pin;
+
+use crate::{Adapter, UnsafeList};
+
+pub struct LinkedList<'a, T, A: Adapter> {
+    list: UnsafeList<T, A>,
+    _phantom_elements: PhantomData<&'a mut T>,
+}
+
+impl<'a, T, A: Adapter> LinkedList<'a, T, A> {
+    pub const fn new() -> Self {
+        Self {
+            list: UnsafeList::new(),
+            _phantom_elements: PhantomData,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        // Safety: `&self` guarantees no mutation of the list and its members
+        // for the duration of the call.
+        unsafe { self.list.is_empty() }
+    }
+
+    pub fn len(&self) -> usize {
+        // Safety: `&self` guarantees no mutation of the list and its members
+        // for the duration of the call.
+        unsafe { self.list.len() }
+    }
+
+    pub fn push_front(&mut self, element: &'a mut T) {
+        // Safety: `&mut self` guarantees exclusive access to the list.  The
+        // list holds the only borrow of `element` for `'a`, so it can not be
+        // in another `LinkedList`.
+        unsafe { self.list.push_front_unchecked(element) }
+    }
+
+    pub fn push_back(&mut self, element: &'a mut T) {
+        // Safety: `&mut self` guarantees exclusive access to the list.  The
+        // list holds the only borrow of `element` for `'a`, so it can not be
+        // in another `LinkedList`.
+        unsafe { self.list.push_back_unchecked(element) }
+    }
+
+    pub fn pop_head(&mut self) -> Option<&'a mut T> {
+        // Safety: `&mut self` guarantees exclusive access to the list.  The
+        // popped element was pushed as `&'a mut T`, so handing back a `&'a mut
+        // T` returns that borrow to the caller.
+        unsafe { self.list.pop_head() }
+    }
+
+    pub fn for_each<E, F: FnMut(&T) -> Result<(), E>>(&self, callback: F) -> Result<(), E> {
+        // Safety: `&self` guarantees no mutation of the list for the duration
+        // of the call and the list holds the only borrows of its members.
+        unsafe { self.list.for_each(callback) }
+    }
+}
+
+impl<T, A: Adapter> Default for LinkedList<'_, T, A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, A: Adapter> Drop for LinkedList<'_, T, A> {
+    fn drop(&mut self) {
+        // Clear the links of any remaining elements before their borrows end.
+        while self.pop_head().is_some() {}
+    }
+}
+
+pub struct PinnedList<'a, T, A: Adapter> {
+    list: UnsafeList<T, A>,
+    _phantom_elements: PhantomData<Pin<&'a mut T>>,
+}
+
+impl<'a, T, A: Adapter> PinnedList<'a, T, A> {
+    pub const fn new() -> Self {
+        Self {
+            list: UnsafeList::new(),
+            _phantom_elements: PhantomData,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        // Safety: `&self` guarantees no mutation of the list and its members
+        // for the duration of the call.
+        unsafe { self.list.is_empty() }
+    }
+
+    pub fn len(&self) -> usize {
+        // Safety: `&self` guarantees no mutation of the list and its members
+        // for the duration of the call.
+        unsafe { self.list.len() }
+    }
+
+    pub fn push_front(&mut self, element: Pin<&'a mut T>) {
+        // Safety: `&mut self` guarantees exclusive access to the list.  The
+        // list holds the only borrow of `element` for `'a`, so it can not be
+        // in another list.  The list never moves its elements and hands them
+        // back pinned, so the pinning guarantee is upheld.
+        unsafe { self.list.push_front_unchecked(element.get_unchecked_mut()) }
+    }
+
+    pub fn push_back(&mut self, element: Pin<&'a mut T>) {
+        // Safety: `&mut self` guarantees exclusive access to the list.  The
+        // list holds the only borrow of `element` for `'a`, so it can not be
+        // in another list.  The list never moves its elements and hands them
+        // back pinned, so the pinning guarantee is upheld.
+        unsafe { self.list.push_back_unchecked(element.get_unchecked_mut()) }
+    }
+
+    pub fn pop_head(&mut self) -> Option<Pin<&'a mut T>> {
+        // Safety: `&mut self` guarantees exclusive access to the list.  The
+        // popped element was pushed as `Pin<&'a mut T>` and is returned
+        // re-pinned.
+        unsafe {
+            self.list
+                .pop_head()
+                .map(|element| Pin::new_unchecked(element))
+        }
+    }
+
+    pub fn for_each<E, F: FnMut(&T) -> Result<(), E>>(&self, callback: F) -> Result<(), E> {
+        // Safety: `&self` guarantees no mutation of the list for the duration
+        // of the call and the list holds the only borrows of its members.
+        unsafe { self.list.for_each(callback) }
+    }
+}
+
+impl<T, A: Adapter> Default for PinnedList<'_, T, A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, A: Adapter> Drop for PinnedList<'_, T, A> {
+    fn drop(&mut self) {
+        // Clear the links of any remaining elements before their borrows end.
+        while self.pop_head().is_some() {}
+    }
+}
@@ -14,7 +14,13 @@
 
 #![no_std]
 #![no_main]
+// The `defmt` feature is enabled through Bazel's `crate_features`, which
+// Cargo's `check-cfg` does not know about.
+#![allow(unexpected_cfgs)]
+use core::marker::PhantomPinned;
 use core::mem::offset_of;
+use core::pin::{pin, Pin};
+use core::ptr::NonNull;
 
 use list::*;
 use unittest::test;
@@ -30,7 +36,7 @@ struct TestMember {
 }
 
 struct TestAdapter {}
-impl Adapter for TestAdapter {
+unsafe impl Adapter for TestAdapter {
     const LINK_OFFSET: usize = offset_of!(TestMember, link);
 }
 
@@ -46,9 +52,65 @@ unsafe fn validate_list(
     })?;
 
     unittest::assert_eq!(index, expected_values.len());
+    unittest::assert_eq!(list.len(), expected_values.len());
+    unittest::assert_true!(list.is_valid());
     Ok(())
 }
 
+// Returns the link of `element` for the functions that take raw links.  The
+// list turns links back into elements, so the pointer has to be derived from
+// the whole element rather than from a reference to its `link` field.
+unsafe fn link_ptr(element: *mut TestMember) -> NonNull<Link> {
+    NonNull::new_unchecked(core::ptr::addr_of_mut!((*element).link))
+}
+
+/// Owns the members of a test list so that tests can build one without
+/// `unsafe`.
+///
+/// The list points into `nodes`, so the builder is `!Unpin` and must be pinned
+/// (e.g. with `pin!`) before anything is pushed.
+struct ListBuilder<const N: usize> {
+    nodes: [TestMember; N],
+    used: usize,
+    list: UnsafeList<TestMember, TestAdapter>,
+    _pinned: PhantomPinned,
+}
+
+impl<const N: usize> ListBuilder<N> {
+    fn new() -> Self {
+        Self {
+            nodes: core::array::from_fn(|_| TestMember {
+                value: 0,
+                link: Link::new(),
+            }),
+            used: 0,
+            list: UnsafeList::new(),
+            _pinned: PhantomPinned,
+        }
+    }
+
+    /// Pushes a member holding `value` onto the back of the list.
+    ///
+    /// Panics if all `N` members have already been pushed.
+    fn push(self: Pin<&mut Self>, value: u32) -> Pin<&mut Self> {
+        // Safety: The builder is pinned so its nodes never move, and nodes at
+        // or past `used` have never been pushed onto the list.
+        unsafe {
+            let builder = self.get_unchecked_mut();
+            let node = &mut builder.nodes[builder.used];
+            node.value = value;
+            builder.list.push_back_unchecked(node);
+            builder.used += 1;
+            Pin::new_unchecked(builder)
+        }
+    }
+
+    fn list_mut(self: Pin<&mut Self>) -> &mut UnsafeList<TestMember, TestAdapter> {
+        // Safety: Handing out the list header does not move the nodes.
+        unsafe { &mut self.get_unchecked_mut().list }
+    }
+}
+
 #[test]
 fn new_link_is_not_linked() -> unittest::Result<()> {
     let link = Link::new();
@@ -61,6 +123,7 @@ fn new_link_is_not_linked() -> unittest::Result<()> {
 fn new_list_is_empty() -> unittest::Result<()> {
     let list = UnsafeList::<TestMember, TestAdapter>::new();
     unittest::assert_true!(unsafe { list.is_empty() });
+    unittest::assert_eq!(unsafe { list.len() }, 0);
     Ok(())
 }
 
@@ -84,6 +147,228 @@ fn push_front_adds_in_correct_order() -> unittest::Result<()> {
     unsafe { validate_list(&list, &[1, 2]) }
 }
 
+#[test]
+fn push_back_adds_in_correct_order() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.push_back_unchecked(&mut element2) };
+
+    unittest::assert_false!(unsafe { list.is_empty() });
+
+    unsafe { validate_list(&list, &[1, 2]) }
+}
+
+#[test]
+fn pop_head_removes_in_correct_order() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.push_back_unchecked(&mut element2) };
+    unsafe { list.push_back_unchecked(&mut element3) };
+
+    for expected in [1, 2, 3] {
+        let value = unsafe { list.pop_head() }.map(|element| element.value);
+        unittest::assert_eq!(value, Some(expected));
+    }
+
+    unittest::assert_true!(unsafe { list.pop_head() }.is_none());
+    unittest::assert_true!(unsafe { list.is_empty() });
+    unittest::assert_true!(element1.link.is_unlinked());
+    unittest::assert_true!(element2.link.is_unlinked());
+    unittest::assert_true!(element3.link.is_unlinked());
+    Ok(())
+}
+
+#[test]
+fn is_poisoned_is_available_in_all_builds() -> unittest::Result<()> {
+    let mut element = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    unittest::assert_false!(element.link.is_poisoned());
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back(&mut element) };
+    let head = unsafe { list.pop_head() };
+    unittest::assert_eq!(
+        head.is_some_and(|element| element.link.is_poisoned()),
+        cfg!(debug_assertions)
+    );
+    Ok(())
+}
+
+// Links are only poisoned in debug builds.
+#[cfg(debug_assertions)]
+#[test]
+fn unlinked_links_are_poisoned() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+    unittest::assert_false!(elements[0].link.is_poisoned());
+
+    // A poisoned link is still recognized as not being in a list.
+    let head = unsafe { list.pop_head() };
+    unittest::assert_true!(head
+        .as_ref()
+        .is_some_and(|element| element.link.is_poisoned() && element.link.is_unlinked()));
+
+    // Linking it again replaces the poison.
+    if let Some(element) = head {
+        unsafe { list.push_back(element) };
+    }
+    unittest::assert_false!(elements[0].link.is_poisoned());
+    unittest::assert_true!(elements[0].link.is_linked());
+
+    let [_, element2, _] = &elements;
+    unsafe { list.unlink_element(element2) };
+    unittest::assert_true!(element2.link.is_poisoned());
+    unsafe { validate_list(&list, &[3, 1]) }
+}
+
+#[test]
+fn pop_tail_removes_in_correct_order() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.push_back_unchecked(&mut element2) };
+    unsafe { list.push_back_unchecked(&mut element3) };
+
+    for expected in [3, 2, 1] {
+        let value = unsafe { list.pop_tail() }.map(|element| element.value);
+        unittest::assert_eq!(value, Some(expected));
+    }
+
+    unittest::assert_true!(unsafe { list.pop_tail() }.is_none());
+    unittest::assert_true!(unsafe { list.is_empty() });
+    unittest::assert_true!(element1.link.is_unlinked());
+    unittest::assert_true!(element2.link.is_unlinked());
+    unittest::assert_true!(element3.link.is_unlinked());
+
+    // With the list empty, pushing to the back must also update `head`.
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { validate_list(&list, &[1]) }
+}
+
+#[test]
+fn replace_head_swaps_head() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+    let [element1, element2, element3] = &mut elements;
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unittest::assert_true!(unsafe { list.replace_head(element1) }.is_none());
+    unsafe { validate_list(&list, &[1])? };
+
+    unsafe { list.push_back_unchecked(element2) };
+    let old = unsafe { list.replace_head(element3) };
+    unittest::assert_eq!(
+        old.map(|element| (element.value, element.link.is_unlinked())),
+        Some((1, true))
+    );
+    unsafe { validate_list(&list, &[3, 2]) }
+}
+
+#[test]
+fn replace_tail_swaps_tail() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+    let [element1, element2, element3] = &mut elements;
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unittest::assert_true!(unsafe { list.replace_tail(element1) }.is_none());
+    unsafe { validate_list(&list, &[1])? };
+
+    unsafe { list.push_front_unchecked(element2) };
+    let old = unsafe { list.replace_tail(element3) };
+    unittest::assert_eq!(
+        old.map(|element| (element.value, element.link.is_unlinked())),
+        Some((1, true))
+    );
+    unsafe { validate_list(&list, &[2, 3]) }
+}
+
+#[test]
+fn peek_on_empty_list_returns_none() -> unittest::Result<()> {
+    let list = UnsafeList::<TestMember, TestAdapter>::new();
+    unittest::assert_true!(unsafe { list.peek_head() }.is_none());
+    unittest::assert_true!(unsafe { list.peek_tail() }.is_none());
+    Ok(())
+}
+
+#[test]
+fn peek_does_not_remove_elements() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.push_back_unchecked(&mut element2) };
+    unsafe { list.push_back_unchecked(&mut element3) };
+
+    let first = unsafe { list.peek_head() }.map(|element| element as *const _);
+    let second = unsafe { list.peek_head() }.map(|element| element as *const _);
+    unittest::assert_eq!(first, Some(&element1 as *const _));
+    unittest::assert_eq!(second, first);
+
+    let first = unsafe { list.peek_tail() }.map(|element| element as *const _);
+    let second = unsafe { list.peek_tail() }.map(|element| element as *const _);
+    unittest::assert_eq!(first, Some(&element3 as *const _));
+    unittest::assert_eq!(second, first);
+
+    unsafe { validate_list(&list, &[1, 2, 3]) }
+}
+
 #[test]
 fn unlink_removes_head_correctly() -> unittest::Result<()> {
     let mut element1 = TestMember {
@@ -159,6 +444,51 @@ fn unlink_removes_middle_correctly() -> unittest::Result<()> {
     unsafe { validate_list(&list, &[1, 3]) }
 }
 
+#[test]
+fn unlink_clears_element_links() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    let [element1, element2, element3] = &elements;
+    unsafe { list.unlink_element(element2) };
+    unittest::assert_true!(element2.link.is_unlinked());
+    unsafe { list.unlink_element(element3) };
+    unittest::assert_true!(element3.link.is_unlinked());
+
+    // Unlinking the sole member empties the list.
+    unsafe { list.unlink_element(element1) };
+    unittest::assert_true!(element1.link.is_unlinked());
+    unsafe { validate_list(&list, &[]) }
+}
+
+// Unlinking an element twice panics when debug assertions are enabled, which
+// the test harness can not catch.
+#[cfg(not(debug_assertions))]
+#[test]
+fn unlink_twice_leaves_list_intact() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    let [element1, element2, _] = &elements;
+    unsafe { list.unlink_element(element2) };
+    unsafe { list.unlink_element(element2) };
+    unsafe { validate_list(&list, &[1, 3])? };
+
+    unsafe { list.unlink_element(element1) };
+    unsafe { list.unlink_element(element1) };
+    unsafe { validate_list(&list, &[3]) }
+}
+
 #[test]
 fn filter_removes_nothing_correctly() -> unittest::Result<()> {
     let mut element1 = TestMember {
@@ -283,3 +613,3746 @@ fn filter_removes_tail_correctly() -> unittest::Result<()> {
 
     unsafe { validate_list(&list, &[1, 2]) }
 }
+
+#[test]
+fn len_tracks_push_pop_and_filter() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+    let mut element4 = TestMember {
+        value: 4,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element2) };
+    unittest::assert_eq!(unsafe { list.len() }, 1);
+    unsafe { list.push_front_unchecked(&mut element1) };
+    unittest::assert_eq!(unsafe { list.len() }, 2);
+    unsafe { list.push_back_unchecked(&mut element3) };
+    unsafe { list.push_back_unchecked(&mut element4) };
+    unittest::assert_eq!(unsafe { list.len() }, 4);
+
+    unsafe { list.pop_head() };
+    unittest::assert_eq!(unsafe { list.len() }, 3);
+
+    unsafe { list.filter(|element| element.value != 3) };
+    unittest::assert_eq!(unsafe { list.len() }, 2);
+
+    unsafe { list.unlink_element(&element4) };
+    unittest::assert_eq!(unsafe { list.len() }, 1);
+
+    unsafe { list.pop_tail() };
+    unittest::assert_eq!(unsafe { list.len() }, 0);
+    unittest::assert_true!(unsafe { list.is_empty() });
+    Ok(())
+}
+
+#[test]
+fn safe_list_pushes_and_pops_in_correct_order() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+
+    let mut list = safe::LinkedList::<TestMember, TestAdapter>::new();
+    unittest::assert_true!(list.is_empty());
+
+    list.push_back(&mut element2);
+    list.push_back(&mut element3);
+    list.push_front(&mut element1);
+    unittest::assert_eq!(list.len(), 3);
+
+    let mut index = 0;
+    list.for_each(|element| {
+        unittest::assert_eq!(element.value, [1, 2, 3][index]);
+        index += 1;
+        Ok(())
+    })?;
+    unittest::assert_eq!(index, 3);
+
+    for expected in [1, 2, 3] {
+        let value = list.pop_head().map(|element| element.value);
+        unittest::assert_eq!(value, Some(expected));
+    }
+    unittest::assert_true!(list.pop_head().is_none());
+    unittest::assert_true!(list.is_empty());
+    Ok(())
+}
+
+#[test]
+fn safe_list_drop_unlinks_remaining_elements() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+
+    {
+        let mut list = safe::LinkedList::<TestMember, TestAdapter>::new();
+        list.push_back(&mut element1);
+        list.push_back(&mut element2);
+    }
+
+    unittest::assert_true!(element1.link.is_unlinked());
+    unittest::assert_true!(element2.link.is_unlinked());
+    Ok(())
+}
+
+#[test]
+fn iter_on_empty_list_yields_nothing() -> unittest::Result<()> {
+    let list = UnsafeList::<TestMember, TestAdapter>::new();
+    unittest::assert_true!(unsafe { list.iter() }.next().is_none());
+    Ok(())
+}
+
+#[test]
+fn iter_on_single_element_list_yields_element() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element1) };
+
+    let mut iter = unsafe { list.iter() };
+    unittest::assert_eq!(iter.next().map(|element| element.value), Some(1));
+    unittest::assert_true!(iter.next().is_none());
+    Ok(())
+}
+
+#[test]
+fn iter_yields_elements_in_order() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.push_back_unchecked(&mut element2) };
+    unsafe { list.push_back_unchecked(&mut element3) };
+
+    let mut iter = unsafe { list.iter() };
+    unittest::assert_eq!(iter.next().map(|element| element.value), Some(1));
+    unittest::assert_eq!(iter.next().map(|element| element.value), Some(2));
+    unittest::assert_eq!(iter.next().map(|element| element.value), Some(3));
+    unittest::assert_true!(iter.next().is_none());
+
+    unittest::assert_eq!(unsafe { list.iter() }.count(), 3);
+    let found = unsafe { list.iter() }.find(|element| element.value == 2);
+    unittest::assert_eq!(
+        found.map(|element| element as *const _),
+        Some(&element2 as *const _)
+    );
+    Ok(())
+}
+
+#[test]
+fn iter_mut_mutations_persist() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.push_back_unchecked(&mut element2) };
+    unsafe { list.push_back_unchecked(&mut element3) };
+
+    let mut visited = 0;
+    for element in unsafe { list.iter_mut() } {
+        element.value -= 1;
+        visited += 1;
+    }
+    unittest::assert_eq!(visited, 3);
+
+    unsafe { validate_list(&list, &[0, 1, 2]) }?;
+
+    // The written elements can still be unlinked through the list.
+    unittest::assert_eq!(
+        unsafe { list.pop_tail() }.map(|element| element.value),
+        Some(2)
+    );
+    unsafe { validate_list(&list, &[0, 1]) }
+}
+
+#[test]
+fn insert_sorted_keeps_list_in_order() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+    let mut element4 = TestMember {
+        value: 4,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    let cmp = |a: &TestMember, b: &TestMember| a.value.cmp(&b.value);
+    unsafe { list.insert_sorted(&mut element3, cmp) };
+    unsafe { list.insert_sorted(&mut element1, cmp) };
+    unsafe { list.insert_sorted(&mut element4, cmp) };
+    unsafe { list.insert_sorted(&mut element2, cmp) };
+
+    let mut iter = unsafe { list.iter() };
+    for expected in [1, 2, 3, 4] {
+        unittest::assert_eq!(iter.next().map(|element| element.value), Some(expected));
+    }
+    unittest::assert_true!(iter.next().is_none());
+    unittest::assert_eq!(unsafe { list.len() }, 4);
+    Ok(())
+}
+
+#[test]
+fn insert_sorted_places_equal_elements_after_existing() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 0,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    let cmp = |a: &TestMember, b: &TestMember| a.value.cmp(&b.value);
+    unsafe { list.insert_sorted(&mut element1, cmp) };
+    unsafe { list.insert_sorted(&mut element2, cmp) };
+    unsafe { list.insert_sorted(&mut element3, cmp) };
+
+    let mut iter = unsafe { list.iter() }.map(|element| element as *const _);
+    unittest::assert_eq!(iter.next(), Some(&element3 as *const _));
+    unittest::assert_eq!(iter.next(), Some(&element1 as *const _));
+    unittest::assert_eq!(iter.next(), Some(&element2 as *const _));
+    unittest::assert_true!(iter.next().is_none());
+    Ok(())
+}
+
+#[test]
+fn get_or_insert_sorted_returns_existing_or_inserted() -> unittest::Result<()> {
+    let mut elements = [1, 3, 5].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+    let mut fresh = TestMember {
+        value: 4,
+        link: Link::new(),
+    };
+    let mut duplicate = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+    let existing_ptr = &elements[1] as *const TestMember;
+    let fresh_ptr = &fresh as *const TestMember;
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+    let cmp = |a: &TestMember, b: &TestMember| a.value.cmp(&b.value);
+
+    let inserted = unsafe { list.get_or_insert_sorted(&mut fresh, cmp) } as *const TestMember;
+    unittest::assert_eq!(inserted, fresh_ptr);
+    unsafe { validate_list(&list, &[1, 3, 4, 5])? };
+
+    let found = unsafe { list.get_or_insert_sorted(&mut duplicate, cmp) } as *const TestMember;
+    unittest::assert_eq!(found, existing_ptr);
+    unittest::assert_true!(duplicate.link.is_unlinked());
+    unsafe { validate_list(&list, &[1, 3, 4, 5]) }
+}
+
+#[test]
+fn cursor_removes_and_inserts_in_middle() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+    let mut element4 = TestMember {
+        value: 4,
+        link: Link::new(),
+    };
+    let mut element5 = TestMember {
+        value: 5,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.push_back_unchecked(&mut element2) };
+    unsafe { list.push_back_unchecked(&mut element3) };
+
+    {
+        let mut cursor = unsafe { list.cursor_front_mut() };
+        unittest::assert_eq!(cursor.current().map(|element| element.value), Some(1));
+        cursor.move_next();
+        unittest::assert_eq!(cursor.current().map(|element| element.value), Some(2));
+
+        let removed = cursor.remove_current().map(|element| element.value);
+        unittest::assert_eq!(removed, Some(2));
+        unittest::assert_eq!(cursor.current().map(|element| element.value), Some(3));
+
+        unsafe { cursor.insert_before(&mut element4) };
+        unsafe { cursor.insert_after(&mut element5) };
+        unittest::assert_eq!(cursor.current().map(|element| element.value), Some(3));
+
+        cursor.move_prev();
+        unittest::assert_eq!(cursor.current().map(|element| element.value), Some(4));
+    }
+
+    unittest::assert_true!(element2.link.is_unlinked());
+    unittest::assert_eq!(unsafe { list.len() }, 4);
+    unsafe { validate_list(&list, &[1, 4, 3, 5]) }
+}
+
+#[test]
+fn cursor_removes_head_and_tail() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.push_back_unchecked(&mut element2) };
+    unsafe { list.push_back_unchecked(&mut element3) };
+
+    {
+        let mut cursor = unsafe { list.cursor_front_mut() };
+        unittest::assert_eq!(
+            cursor.remove_current().map(|element| element.value),
+            Some(1)
+        );
+        unittest::assert_eq!(cursor.current().map(|element| element.value), Some(2));
+
+        cursor.move_next();
+        unittest::assert_eq!(
+            cursor.remove_current().map(|element| element.value),
+            Some(3)
+        );
+
+        // Removing the tail leaves the cursor on the ghost.
+        unittest::assert_true!(cursor.current().is_none());
+        unittest::assert_true!(cursor.remove_current().is_none());
+    }
+
+    unittest::assert_eq!(
+        unsafe { list.peek_head() }.map(|element| element.value),
+        Some(2)
+    );
+    unittest::assert_eq!(
+        unsafe { list.peek_tail() }.map(|element| element.value),
+        Some(2)
+    );
+    unsafe { validate_list(&list, &[2]) }
+}
+
+#[test]
+fn cursor_at_starts_on_element() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+    let [_, _, element3, _] = &elements;
+
+    {
+        let mut cursor = unsafe { list.cursor_at(element3) };
+        unittest::assert_eq!(cursor.current().map(|element| element.value), Some(3));
+        cursor.move_next();
+        unittest::assert_eq!(cursor.current().map(|element| element.value), Some(4));
+        cursor.move_prev();
+        cursor.move_prev();
+        unittest::assert_eq!(cursor.current().map(|element| element.value), Some(2));
+
+        let removed = cursor.remove_current().map(|element| element.value);
+        unittest::assert_eq!(removed, Some(2));
+        unittest::assert_eq!(cursor.current().map(|element| element.value), Some(3));
+    }
+
+    unsafe { validate_list(&list, &[1, 3, 4]) }
+}
+
+#[test]
+fn cursor_inserts_into_empty_list() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+
+    {
+        let mut cursor = unsafe { list.cursor_front_mut() };
+        unittest::assert_true!(cursor.current().is_none());
+
+        // On the ghost, `insert_after` pushes to the front and `insert_before`
+        // pushes to the back.
+        unsafe { cursor.insert_after(&mut element2) };
+        unsafe { cursor.insert_after(&mut element1) };
+        unsafe { cursor.insert_before(&mut element3) };
+        unittest::assert_true!(cursor.current().is_none());
+
+        cursor.move_next();
+        unittest::assert_eq!(cursor.current().map(|element| element.value), Some(1));
+        cursor.move_prev();
+        cursor.move_prev();
+        unittest::assert_eq!(cursor.current().map(|element| element.value), Some(3));
+    }
+
+    unsafe { validate_list(&list, &[1, 2, 3]) }
+}
+
+#[test]
+fn append_moves_other_onto_back() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+    let mut element4 = TestMember {
+        value: 4,
+        link: Link::new(),
+    };
+    let mut element5 = TestMember {
+        value: 5,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.push_back_unchecked(&mut element2) };
+    unsafe { list.push_back_unchecked(&mut element3) };
+
+    let mut other = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { other.push_back_unchecked(&mut element4) };
+    unsafe { other.push_back_unchecked(&mut element5) };
+
+    unsafe { list.append(&mut other) };
+
+    unittest::assert_true!(unsafe { other.is_empty() });
+    unittest::assert_eq!(unsafe { other.len() }, 0);
+    unittest::assert_eq!(unsafe { list.len() }, 5);
+    unittest::assert_eq!(
+        unsafe { list.peek_tail() }.map(|element| element.value),
+        Some(5)
+    );
+    unsafe { validate_list(&list, &[1, 2, 3, 4, 5]) }
+}
+
+#[test]
+fn append_handles_empty_lists() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    let mut other = UnsafeList::<TestMember, TestAdapter>::new();
+
+    // Appending an empty list onto an empty list.
+    unsafe { list.append(&mut other) };
+    unittest::assert_true!(unsafe { list.is_empty() });
+
+    // Appending onto an empty list.
+    unsafe { other.push_back_unchecked(&mut element1) };
+    unsafe { other.push_back_unchecked(&mut element2) };
+    unsafe { list.append(&mut other) };
+    unittest::assert_true!(unsafe { other.is_empty() });
+    unsafe { validate_list(&list, &[1, 2]) }?;
+
+    // Appending an empty list.
+    unsafe { list.append(&mut other) };
+    unittest::assert_eq!(unsafe { list.len() }, 2);
+    unsafe { validate_list(&list, &[1, 2]) }
+}
+
+#[test]
+fn prepend_moves_other_onto_front() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element3) };
+
+    let mut other = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { other.push_back_unchecked(&mut element1) };
+    unsafe { other.push_back_unchecked(&mut element2) };
+
+    unsafe { list.prepend(&mut other) };
+
+    unittest::assert_true!(unsafe { other.is_empty() });
+    unittest::assert_eq!(unsafe { list.len() }, 3);
+    unittest::assert_eq!(
+        unsafe { list.peek_head() }.map(|element| element.value),
+        Some(1)
+    );
+    unittest::assert_eq!(
+        unsafe { list.peek_tail() }.map(|element| element.value),
+        Some(3)
+    );
+    unsafe { validate_list(&list, &[1, 2, 3]) }
+}
+
+#[test]
+fn prepend_onto_empty_list_sets_tail() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    let mut other = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { other.push_back_unchecked(&mut element1) };
+    unsafe { other.push_back_unchecked(&mut element2) };
+
+    unsafe { list.prepend(&mut other) };
+
+    unittest::assert_true!(unsafe { other.is_empty() });
+    unittest::assert_eq!(
+        unsafe { list.peek_tail() }.map(|element| element.value),
+        Some(2)
+    );
+
+    // Pushing to the back relies on `tail` having been picked up from `other`.
+    unsafe { list.push_back_unchecked(&mut element3) };
+    unsafe { validate_list(&list, &[1, 2, 3]) }
+}
+
+#[test]
+fn contains_only_reports_members_of_the_list() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+    let element4 = TestMember {
+        value: 4,
+        link: Link::new(),
+    };
+
+    let mut list_a = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list_a.push_back_unchecked(&mut element1) };
+    unsafe { list_a.push_back_unchecked(&mut element2) };
+
+    let mut list_b = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list_b.push_back_unchecked(&mut element3) };
+
+    unittest::assert_true!(unsafe { list_a.contains(&element1) });
+    unittest::assert_true!(unsafe { list_a.contains(&element2) });
+    unittest::assert_false!(unsafe { list_a.contains(&element3) });
+    unittest::assert_false!(unsafe { list_a.contains(&element4) });
+
+    // `element3` is the only member of `list_b` so it has no neighbors.
+    unittest::assert_true!(unsafe { list_b.contains(&element3) });
+    unittest::assert_false!(unsafe { list_b.contains(&element1) });
+    unittest::assert_false!(unsafe { list_b.contains(&element4) });
+    Ok(())
+}
+
+#[test]
+fn retain_returns_number_of_removed_elements() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+    let mut element4 = TestMember {
+        value: 4,
+        link: Link::new(),
+    };
+    let mut element5 = TestMember {
+        value: 5,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.push_back_unchecked(&mut element2) };
+    unsafe { list.push_back_unchecked(&mut element3) };
+    unsafe { list.push_back_unchecked(&mut element4) };
+    unsafe { list.push_back_unchecked(&mut element5) };
+
+    let removed = unsafe { list.retain(|element| element.value % 2 != 0) };
+    unittest::assert_eq!(removed, 2);
+    unittest::assert_eq!(unsafe { list.len() }, 3);
+    unsafe { validate_list(&list, &[1, 3, 5]) }?;
+
+    let removed = unsafe { list.retain(|_| true) };
+    unittest::assert_eq!(removed, 0);
+    unsafe { validate_list(&list, &[1, 3, 5]) }
+}
+
+#[derive(Adapter)]
+#[repr(C)]
+struct DerivedMember {
+    value: u32,
+    #[list_link]
+    link: Link,
+}
+
+#[test]
+fn derived_adapter_links_elements() -> unittest::Result<()> {
+    unittest::assert_eq!(DerivedMember::LINK_OFFSET, offset_of!(DerivedMember, link));
+    unittest::assert_eq!(
+        DerivedMember::ADAPTER_TYPE_SIZE,
+        core::mem::size_of::<DerivedMember>()
+    );
+
+    let mut element1 = DerivedMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = DerivedMember {
+        value: 2,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<DerivedMember, DerivedMember>::new();
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.push_back_unchecked(&mut element2) };
+
+    let mut iter = unsafe { list.iter() };
+    unittest::assert_eq!(iter.next().map(|element| element.value), Some(1));
+    unittest::assert_eq!(iter.next().map(|element| element.value), Some(2));
+    unittest::assert_true!(iter.next().is_none());
+    Ok(())
+}
+
+#[repr(C)]
+struct MultiListMember {
+    value: u32,
+    all_link: Link,
+    ready_link: Link,
+}
+
+struct AllAdapter {}
+unsafe impl Adapter for AllAdapter {
+    const LINK_OFFSET: usize = offset_of!(MultiListMember, all_link);
+}
+
+struct ReadyAdapter {}
+unsafe impl Adapter for ReadyAdapter {
+    const LINK_OFFSET: usize = offset_of!(MultiListMember, ready_link);
+}
+
+unsafe fn validate_multi_list<A: Adapter>(
+    list: &UnsafeList<MultiListMember, A>,
+    expected_values: &[u32],
+) -> unittest::Result<()> {
+    let mut index = 0;
+    list.for_each(|element| {
+        unittest::assert_eq!(element.value, expected_values[index]);
+        index += 1;
+        Ok(())
+    })?;
+
+    unittest::assert_eq!(index, expected_values.len());
+    Ok(())
+}
+
+#[test]
+fn element_can_be_in_two_lists_through_two_adapters() -> unittest::Result<()> {
+    let mut element1 = MultiListMember {
+        value: 1,
+        all_link: Link::new(),
+        ready_link: Link::new(),
+    };
+    let mut element2 = MultiListMember {
+        value: 2,
+        all_link: Link::new(),
+        ready_link: Link::new(),
+    };
+    let mut element3 = MultiListMember {
+        value: 3,
+        all_link: Link::new(),
+        ready_link: Link::new(),
+    };
+
+    let mut all = UnsafeList::<MultiListMember, AllAdapter>::new();
+    let mut ready = UnsafeList::<MultiListMember, ReadyAdapter>::new();
+    unsafe { all.push_back_unchecked(&mut element1) };
+    unsafe { all.push_back_unchecked(&mut element2) };
+    unsafe { all.push_back_unchecked(&mut element3) };
+    unsafe { ready.push_back_unchecked(&mut element3) };
+    unsafe { ready.push_back_unchecked(&mut element2) };
+
+    unsafe { validate_multi_list(&all, &[1, 2, 3]) }?;
+    unsafe { validate_multi_list(&ready, &[3, 2]) }?;
+
+    // Unlinking from one list leaves the other untouched.
+    unsafe { ready.unlink_element(&element3) };
+    unsafe { validate_multi_list(&all, &[1, 2, 3]) }?;
+    unsafe { validate_multi_list(&ready, &[2]) }?;
+
+    unsafe { all.unlink_element(&element2) };
+    unsafe { validate_multi_list(&all, &[1, 3]) }?;
+    unsafe { validate_multi_list(&ready, &[2]) }?;
+
+    unittest::assert_eq!(unsafe { all.len() }, 2);
+    unittest::assert_eq!(unsafe { ready.len() }, 1);
+    Ok(())
+}
+
+#[test]
+fn is_valid_detects_corrupted_prev_link() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+    let mut element4 = TestMember {
+        value: 4,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.push_back_unchecked(&mut element2) };
+    unsafe { list.push_back_unchecked(&mut element3) };
+    unittest::assert_true!(unsafe { list.is_valid() });
+
+    // Deliberately break the "not in a list" precondition to overwrite
+    // `element2`'s links with ones pointing into `other`.
+    let mut other = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { other.push_back_unchecked(&mut element4) };
+    unsafe { other.push_back_unchecked(&mut element2) };
+
+    // `validate()` panics on the same condition.  The test harness can not
+    // catch panics so `is_valid()` is checked here and the panic is covered
+    // by `validate`'s doc test.
+    unittest::assert_false!(unsafe { list.is_valid() });
+    Ok(())
+}
+
+#[test]
+fn clear_unlinks_every_element() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.push_back_unchecked(&mut element2) };
+    unsafe { list.push_back_unchecked(&mut element3) };
+
+    unsafe { list.clear() };
+
+    unittest::assert_true!(unsafe { list.is_empty() });
+    unittest::assert_true!(element1.link.is_unlinked());
+    unittest::assert_true!(element2.link.is_unlinked());
+    unittest::assert_true!(element3.link.is_unlinked());
+    unsafe { validate_list(&list, &[]) }?;
+
+    // The list and elements can be reused after clearing.
+    unsafe { list.push_back_unchecked(&mut element2) };
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { validate_list(&list, &[2, 1]) }
+}
+
+#[test]
+fn drain_dropped_early_leaves_remaining_elements() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+    let mut element4 = TestMember {
+        value: 4,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.push_back_unchecked(&mut element2) };
+    unsafe { list.push_back_unchecked(&mut element3) };
+    unsafe { list.push_back_unchecked(&mut element4) };
+
+    {
+        let mut drain = unsafe { list.drain() };
+        unittest::assert_eq!(drain.next().map(|element| element.value), Some(1));
+        unittest::assert_eq!(drain.next().map(|element| element.value), Some(2));
+    }
+
+    unittest::assert_true!(element1.link.is_unlinked());
+    unittest::assert_true!(element2.link.is_unlinked());
+    unittest::assert_true!(element3.link.is_linked());
+    unittest::assert_true!(element4.link.is_linked());
+    unsafe { validate_list(&list, &[3, 4]) }
+}
+
+#[test]
+fn drain_empties_list() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.push_back_unchecked(&mut element2) };
+
+    unittest::assert_eq!(unsafe { list.drain() }.count(), 2);
+    unittest::assert_true!(element1.link.is_unlinked());
+    unittest::assert_true!(element2.link.is_unlinked());
+    unsafe { validate_list(&list, &[]) }
+}
+
+#[test]
+fn insert_before_head_updates_head() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element3) };
+    unsafe { list.insert_before(&element3, &mut element2) };
+    unsafe { list.insert_before(&element2, &mut element1) };
+
+    unittest::assert_eq!(
+        unsafe { list.peek_head() }.map(|element| element.value),
+        Some(1)
+    );
+    unsafe { validate_list(&list, &[1, 2, 3]) }
+}
+
+#[test]
+fn insert_after_tail_updates_tail() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.insert_after(&element1, &mut element2) };
+    unsafe { list.insert_after(&element2, &mut element3) };
+
+    unittest::assert_eq!(
+        unsafe { list.peek_tail() }.map(|element| element.value),
+        Some(3)
+    );
+    unsafe { validate_list(&list, &[1, 2, 3]) }
+}
+
+#[test]
+fn insert_relative_to_middle_element() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+    let mut element4 = TestMember {
+        value: 4,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.push_back_unchecked(&mut element4) };
+    unsafe { list.insert_after(&element1, &mut element2) };
+    unsafe { list.insert_before(&element4, &mut element3) };
+
+    unsafe { validate_list(&list, &[1, 2, 3, 4]) }
+}
+
+#[test]
+fn move_to_front_moves_middle_element_to_head() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+    let mut element4 = TestMember {
+        value: 4,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.push_back_unchecked(&mut element2) };
+    unsafe { list.push_back_unchecked(&mut element3) };
+    unsafe { list.push_back_unchecked(&mut element4) };
+
+    unsafe { list.move_to_front(&mut element3) };
+    unsafe { validate_list(&list, &[3, 1, 2, 4]) }?;
+
+    // Moving the head to the front is a no-op.
+    unsafe { list.move_to_front(&mut element3) };
+    unsafe { validate_list(&list, &[3, 1, 2, 4]) }
+}
+
+#[test]
+fn move_to_back_moves_middle_element_to_tail() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+    let mut element4 = TestMember {
+        value: 4,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.push_back_unchecked(&mut element2) };
+    unsafe { list.push_back_unchecked(&mut element3) };
+    unsafe { list.push_back_unchecked(&mut element4) };
+
+    unsafe { list.move_to_back(&mut element2) };
+    unsafe { validate_list(&list, &[1, 3, 4, 2]) }?;
+
+    // Moving the tail to the back is a no-op.
+    unsafe { list.move_to_back(&mut element2) };
+    unsafe { validate_list(&list, &[1, 3, 4, 2]) }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+#[test]
+fn atomic_list_pop_all_returns_elements_in_push_order() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+
+    let atomic_list = atomic::AtomicList::<TestMember, TestAdapter>::new();
+    unittest::assert_true!(atomic_list.is_empty());
+    unittest::assert_true!(unsafe { atomic_list.pop_all().is_empty() });
+
+    unsafe { atomic_list.push(&mut element1) };
+    unsafe { atomic_list.push(&mut element2) };
+    unittest::assert_false!(atomic_list.is_empty());
+
+    let list = atomic_list.pop_all();
+    unittest::assert_true!(atomic_list.is_empty());
+    unsafe { validate_list(&list, &[1, 2]) }?;
+
+    // Elements pushed after `pop_all` are left for the next call.
+    unsafe { atomic_list.push(&mut element3) };
+    let list = atomic_list.pop_all();
+    unsafe { validate_list(&list, &[3]) }
+}
+
+#[test]
+fn reverse_reverses_element_order() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.reverse() };
+    unsafe { validate_list(&list, &[]) }?;
+
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.reverse() };
+    unsafe { validate_list(&list, &[1]) }?;
+
+    unsafe { list.push_back_unchecked(&mut element2) };
+    unsafe { list.push_back_unchecked(&mut element3) };
+    unsafe { list.reverse() };
+
+    let mut iter = unsafe { list.iter() };
+    for expected in [3, 2, 1] {
+        unittest::assert_eq!(iter.next().map(|element| element.value), Some(expected));
+    }
+    unittest::assert_true!(iter.next().is_none());
+
+    // `validate_list` also checks that every `prev` link agrees with `next`.
+    unsafe { validate_list(&list, &[3, 2, 1]) }?;
+    unittest::assert_eq!(
+        unsafe { list.pop_tail() }.map(|element| element.value),
+        Some(1)
+    );
+    unsafe { validate_list(&list, &[3, 2]) }
+}
+
+#[test]
+fn reverse_between_reverses_range() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4, 5].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+    let [element1, element2, element3, element4, element5] = &elements;
+
+    // Interior.
+    unsafe { list.reverse_between(element2, element4) };
+    unsafe { validate_list(&list, &[1, 4, 3, 2, 5])? };
+
+    // Touching the head.
+    unsafe { list.reverse_between(element1, element3) };
+    unsafe { validate_list(&list, &[3, 4, 1, 2, 5])? };
+
+    // Touching the tail.
+    unsafe { list.reverse_between(element2, element5) };
+    unsafe { validate_list(&list, &[3, 4, 1, 5, 2])? };
+
+    // A single element.
+    unsafe { list.reverse_between(element1, element1) };
+    unsafe { validate_list(&list, &[3, 4, 1, 5, 2])? };
+
+    // The whole list.
+    unsafe { list.reverse_between(element3, element2) };
+    unsafe { validate_list(&list, &[2, 5, 1, 4, 3])? };
+    unittest::assert_eq!(
+        unsafe { list.peek_head() }.map(|element| element.value),
+        Some(2)
+    );
+    unittest::assert_eq!(
+        unsafe { list.peek_tail() }.map(|element| element.value),
+        Some(3)
+    );
+    Ok(())
+}
+
+#[test]
+fn split_after_middle_element() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+    let mut element4 = TestMember {
+        value: 4,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.push_back_unchecked(&mut element2) };
+    unsafe { list.push_back_unchecked(&mut element3) };
+    unsafe { list.push_back_unchecked(&mut element4) };
+
+    let other = unsafe { list.split_after(&element2) };
+
+    unittest::assert_eq!(
+        unsafe { list.peek_tail() }.map(|element| element.value),
+        Some(2)
+    );
+    unittest::assert_eq!(
+        unsafe { other.peek_head() }.map(|element| element.value),
+        Some(3)
+    );
+    unittest::assert_eq!(
+        unsafe { other.peek_tail() }.map(|element| element.value),
+        Some(4)
+    );
+    unsafe { validate_list(&list, &[1, 2]) }?;
+    unsafe { validate_list(&other, &[3, 4]) }
+}
+
+#[test]
+fn split_after_tail_returns_empty_list() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.push_back_unchecked(&mut element2) };
+
+    let other = unsafe { list.split_after(&element2) };
+
+    unsafe { validate_list(&list, &[1, 2]) }?;
+    unsafe { validate_list(&other, &[]) }
+}
+
+#[test]
+fn split_off_splits_at_index() -> unittest::Result<()> {
+    let mut builder = pin!(ListBuilder::<4>::new());
+    builder.as_mut().push(1).push(2).push(3).push(4);
+    let list = builder.as_mut().list_mut();
+
+    // Splitting at the length leaves everything in place.
+    let mut other = unsafe { list.split_off(4) };
+    unsafe { validate_list(list, &[1, 2, 3, 4]) }?;
+    unsafe { validate_list(&other, &[]) }?;
+
+    // Splitting in the middle keeps the front in `list`.
+    other = unsafe { list.split_off(1) };
+    unsafe { validate_list(list, &[1]) }?;
+    unsafe { validate_list(&other, &[2, 3, 4]) }?;
+
+    // Splitting at zero moves everything out.
+    let rest = unsafe { other.split_off(0) };
+    unsafe { validate_list(&other, &[]) }?;
+    unsafe { validate_list(&rest, &[2, 3, 4]) }?;
+    unittest::assert_eq!(
+        unsafe { rest.peek_tail() }.map(|element| element.value),
+        Some(4)
+    );
+    Ok(())
+}
+
+#[test]
+fn front_and_back_ptr_point_at_elements() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unittest::assert_true!(unsafe { list.front_ptr() }.is_none());
+    unittest::assert_true!(unsafe { list.back_ptr() }.is_none());
+
+    unsafe { list.push_front_unchecked(&mut element2) };
+    unsafe { list.push_front_unchecked(&mut element1) };
+
+    unittest::assert_eq!(unsafe { list.front_ptr() }, Some(&element1 as *const _));
+    unittest::assert_eq!(unsafe { list.back_ptr() }, Some(&element2 as *const _));
+    Ok(())
+}
+
+#[test]
+fn swap_nodes_swaps_adjacent_elements() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+    let mut element4 = TestMember {
+        value: 4,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.push_back_unchecked(&mut element2) };
+    unsafe { list.push_back_unchecked(&mut element3) };
+    unsafe { list.push_back_unchecked(&mut element4) };
+
+    unsafe { list.swap_nodes(&mut element2, &mut element3) };
+    unsafe { validate_list(&list, &[1, 3, 2, 4]) }?;
+
+    // Arguments in reverse list order.
+    unsafe { list.swap_nodes(&mut element2, &mut element3) };
+    unsafe { validate_list(&list, &[1, 2, 3, 4]) }?;
+
+    // Adjacent at the head and at the tail.
+    unsafe { list.swap_nodes(&mut element1, &mut element2) };
+    unsafe { validate_list(&list, &[2, 1, 3, 4]) }?;
+    unsafe { list.swap_nodes(&mut element4, &mut element3) };
+    unsafe { validate_list(&list, &[2, 1, 4, 3]) }
+}
+
+#[test]
+fn swap_nodes_swaps_head_and_tail() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.push_back_unchecked(&mut element2) };
+    unsafe { list.push_back_unchecked(&mut element3) };
+
+    unsafe { list.swap_nodes(&mut element1, &mut element3) };
+    unsafe { validate_list(&list, &[3, 2, 1]) }?;
+
+    // Two element list where head and tail are adjacent.
+    unsafe { list.unlink_element(&element2) };
+    unsafe { list.swap_nodes(&mut element3, &mut element1) };
+    unsafe { validate_list(&list, &[1, 3]) }
+}
+
+#[test]
+fn swap_nodes_with_non_adjacent_middle_elements() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+    let mut element4 = TestMember {
+        value: 4,
+        link: Link::new(),
+    };
+    let mut element5 = TestMember {
+        value: 5,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.push_back_unchecked(&mut element2) };
+    unsafe { list.push_back_unchecked(&mut element3) };
+    unsafe { list.push_back_unchecked(&mut element4) };
+    unsafe { list.push_back_unchecked(&mut element5) };
+
+    unsafe { list.swap_nodes(&mut element4, &mut element2) };
+    unsafe { validate_list(&list, &[1, 4, 3, 2, 5]) }
+}
+
+#[test]
+fn swap_nodes_with_itself_is_a_no_op() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.push_back_unchecked(&mut element2) };
+
+    // `swap_nodes` takes two mutable references, so the same element is
+    // passed in through a raw pointer.
+    let element_ptr: *mut TestMember = &mut element2;
+    unsafe { list.swap_nodes(&mut *element_ptr, &mut *element_ptr) };
+    unsafe { validate_list(&list, &[1, 2]) }
+}
+
+unsafe fn validate_sorted(values: &[u32], expected_values: &[u32]) -> unittest::Result<()> {
+    let mut elements = [0; 8].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    for (element, value) in elements.iter_mut().zip(values) {
+        element.value = *value;
+        list.push_back_unchecked(element);
+    }
+
+    list.sort_by(|a, b| a.value.cmp(&b.value));
+    validate_list(&list, expected_values)
+}
+
+#[test]
+fn sort_by_sorts_lists() -> unittest::Result<()> {
+    unsafe { validate_sorted(&[], &[]) }?;
+    unsafe { validate_sorted(&[1], &[1]) }?;
+    unsafe { validate_sorted(&[1, 2, 3, 4, 5], &[1, 2, 3, 4, 5]) }?;
+    unsafe { validate_sorted(&[5, 4, 3, 2, 1], &[1, 2, 3, 4, 5]) }?;
+    unsafe { validate_sorted(&[3, 7, 1, 8, 2, 6, 5, 4], &[1, 2, 3, 4, 5, 6, 7, 8]) }?;
+    unsafe { validate_sorted(&[2, 1, 2, 1, 3], &[1, 1, 2, 2, 3]) }
+}
+
+#[test]
+fn sort_by_is_stable() -> unittest::Result<()> {
+    // The tens digit is the sort key and the ones digit records the original
+    // order.
+    let mut elements = [31, 10, 20, 32, 11, 33, 21].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    for element in elements.iter_mut() {
+        unsafe { list.push_back_unchecked(element) };
+    }
+
+    unsafe { list.sort_by(|a, b| (a.value / 10).cmp(&(b.value / 10))) };
+    unsafe { validate_list(&list, &[10, 11, 20, 21, 31, 32, 33]) }
+}
+
+#[test]
+fn rotate_left_moves_elements_to_back() -> unittest::Result<()> {
+    let mut builder = pin!(ListBuilder::<4>::new());
+    builder.as_mut().push(1).push(2).push(3).push(4);
+    let list = builder.as_mut().list_mut();
+
+    unsafe { list.rotate_left(0) };
+    unsafe { validate_list(list, &[1, 2, 3, 4]) }?;
+
+    unsafe { list.rotate_left(4) };
+    unsafe { validate_list(list, &[1, 2, 3, 4]) }?;
+
+    unsafe { list.rotate_left(1) };
+    unsafe { validate_list(list, &[2, 3, 4, 1]) }?;
+
+    unsafe { list.rotate_left(3) };
+    unsafe { validate_list(list, &[1, 2, 3, 4]) }?;
+
+    // 6 wraps to 2.
+    unsafe { list.rotate_left(6) };
+    unsafe { validate_list(list, &[3, 4, 1, 2]) }
+}
+
+#[test]
+fn rotate_right_moves_elements_to_front() -> unittest::Result<()> {
+    let mut builder = pin!(ListBuilder::<4>::new());
+    builder.as_mut().push(1).push(2).push(3).push(4);
+    let list = builder.as_mut().list_mut();
+
+    unsafe { list.rotate_right(0) };
+    unsafe { validate_list(list, &[1, 2, 3, 4]) }?;
+
+    unsafe { list.rotate_right(4) };
+    unsafe { validate_list(list, &[1, 2, 3, 4]) }?;
+
+    unsafe { list.rotate_right(1) };
+    unsafe { validate_list(list, &[4, 1, 2, 3]) }?;
+
+    // 5 wraps to 1.
+    unsafe { list.rotate_right(5) };
+    unsafe { validate_list(list, &[3, 4, 1, 2]) }
+}
+
+#[test]
+fn rotate_on_empty_and_single_element_lists() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.rotate_left(3) };
+    unsafe { list.rotate_right(3) };
+    unsafe { validate_list(&list, &[]) }?;
+
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.rotate_left(3) };
+    unsafe { list.rotate_right(3) };
+    unsafe { validate_list(&list, &[1]) }
+}
+
+#[test]
+fn find_mut_returns_first_match() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 2].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    for element in elements.iter_mut() {
+        unsafe { list.push_back_unchecked(element) };
+    }
+
+    if let Some(head) = unsafe { list.find_mut(|element| element.value == 1) } {
+        head.value = 10;
+    }
+    if let Some(middle) = unsafe { list.find_mut(|element| element.value == 2) } {
+        middle.value = 20;
+    }
+    unittest::assert_true!(unsafe { list.find_mut(|element| element.value == 5) }.is_none());
+
+    // Only the first matching element is modified and both stay linked.
+    unsafe { validate_list(&list, &[10, 20, 3, 2]) }?;
+    unittest::assert_eq!(
+        unsafe { list.pop_head() }.map(|element| element.value),
+        Some(10)
+    );
+    unsafe { validate_list(&list, &[20, 3, 2]) }
+}
+
+#[test]
+fn rfind_mut_returns_last_match() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 2].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    for element in elements.iter_mut() {
+        unsafe { list.push_back_unchecked(element) };
+    }
+
+    if let Some(tail) = unsafe { list.rfind_mut(|element| element.value == 2) } {
+        tail.value = 20;
+    }
+    if let Some(head) = unsafe { list.rfind_mut(|element| element.value == 1) } {
+        head.value = 10;
+    }
+    unittest::assert_true!(unsafe { list.rfind_mut(|element| element.value == 5) }.is_none());
+
+    // Unlike `find_mut`, only the last matching element is modified.
+    unsafe { validate_list(&list, &[10, 2, 3, 20]) }?;
+    unittest::assert_eq!(
+        unsafe { list.pop_tail() }.map(|element| element.value),
+        Some(20)
+    );
+    unsafe { validate_list(&list, &[10, 2, 3]) }
+}
+
+#[test]
+fn first_and_last_where_search_from_each_end() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+    let [_, element2, _, element4] = &elements;
+
+    // Both lookups borrow the list immutably at the same time.
+    let first = unsafe { list.first_where(|element| element.value % 2 == 0) };
+    let last = unsafe { list.last_where(|element| element.value % 2 == 0) };
+    unittest::assert_eq!(
+        first.map(|element| element as *const _),
+        Some(element2 as *const _)
+    );
+    unittest::assert_eq!(
+        last.map(|element| element as *const _),
+        Some(element4 as *const _)
+    );
+
+    unittest::assert_true!(unsafe { list.first_where(|element| element.value > 4) }.is_none());
+    unittest::assert_true!(unsafe { list.last_where(|element| element.value > 4) }.is_none());
+    Ok(())
+}
+
+#[test]
+fn position_returns_index_of_member() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+    let mut element3 = TestMember {
+        value: 3,
+        link: Link::new(),
+    };
+    let mut element4 = TestMember {
+        value: 4,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.push_back_unchecked(&mut element2) };
+    unsafe { list.push_back_unchecked(&mut element3) };
+
+    let mut other = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { other.push_back_unchecked(&mut element4) };
+
+    unittest::assert_eq!(unsafe { list.position(&element1) }, Some(0));
+    unittest::assert_eq!(unsafe { list.position(&element2) }, Some(1));
+    unittest::assert_eq!(
+        unsafe { list.position(&element3) },
+        Some(unsafe { list.len() } - 1)
+    );
+    unittest::assert_eq!(unsafe { list.position(&element4) }, None);
+    Ok(())
+}
+
+#[test]
+fn index_and_len_counts_in_one_walk() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+    let outsider = TestMember {
+        value: 5,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unittest::assert_eq!(unsafe { list.index_and_len(&outsider) }, (None, 0));
+
+    unsafe { list.extend_back(elements.iter_mut()) };
+    let [element1, _, element3, element4] = &elements;
+    unittest::assert_eq!(unsafe { list.index_and_len(element1) }, (Some(0), 4));
+    unittest::assert_eq!(unsafe { list.index_and_len(element3) }, (Some(2), 4));
+    unittest::assert_eq!(unsafe { list.index_and_len(element4) }, (Some(3), 4));
+    unittest::assert_eq!(unsafe { list.index_and_len(&outsider) }, (None, 4));
+    Ok(())
+}
+
+fn assert_send<T: Send>() {}
+
+fn assert_sync<T: Sync>() {}
+
+#[test]
+fn unsafe_list_is_send() -> unittest::Result<()> {
+    // Compile-time check: a list of `Send` elements can be moved between
+    // threads, e.g. behind a lock.
+    assert_send::<UnsafeList<TestMember, TestAdapter>>();
+    Ok(())
+}
+
+#[test]
+fn unsafe_list_is_sync() -> unittest::Result<()> {
+    // Compile-time check: a list of elements that hold only a `Link` and
+    // `Sync` fields can be shared between threads without opting in.
+    assert_sync::<UnsafeList<TestMember, TestAdapter>>();
+    Ok(())
+}
+
+#[derive(Adapter)]
+#[repr(C)]
+struct StaticMember {
+    #[list_link]
+    link: Link,
+}
+
+static STATIC_LIST: UnsafeList<StaticMember, StaticMember> = UnsafeList::new();
+
+// Compile-time check: a `static` list header can be inspected during const
+// evaluation.
+const _: () = assert!(STATIC_LIST.is_empty_const());
+
+#[test]
+fn static_list_is_empty() -> unittest::Result<()> {
+    unittest::assert_true!(STATIC_LIST.is_empty_const());
+    unittest::assert_true!(unsafe { STATIC_LIST.is_empty() });
+    Ok(())
+}
+
+#[test]
+fn extend_back_pushes_in_iteration_order() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+    let mut element5 = TestMember {
+        value: 5,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element5) };
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    unsafe { validate_list(&list, &[5, 1, 2, 3, 4]) }
+}
+
+#[test]
+fn extend_back_with_empty_iterator_leaves_list_untouched() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(core::iter::empty()) };
+    unsafe { validate_list(&list, &[])? };
+
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.extend_back(core::iter::empty()) };
+    unsafe { validate_list(&list, &[1]) }
+}
+
+unsafe fn validate_circular_list(
+    list: &circular::CircularList<TestMember, TestAdapter>,
+    expected: &[u32],
+) -> unittest::Result<()> {
+    unittest::assert_eq!(list.len(), expected.len());
+    unittest::assert_eq!(
+        list.peek_head().map(|element| element.value),
+        expected.first().copied()
+    );
+    unittest::assert_eq!(
+        list.peek_tail().map(|element| element.value),
+        expected.last().copied()
+    );
+
+    // Walk forward and backward twice around the ring.
+    let mut cursor = list.cursor();
+    for i in 0..expected.len() * 2 {
+        unittest::assert_eq!(
+            cursor.current().map(|element| element.value),
+            Some(expected[i % expected.len()])
+        );
+        cursor.move_next();
+    }
+    for i in (0..expected.len() * 2).rev() {
+        cursor.move_prev();
+        unittest::assert_eq!(
+            cursor.current().map(|element| element.value),
+            Some(expected[i % expected.len()])
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn circular_list_cursor_cycles() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+    let mut element0 = TestMember {
+        value: 0,
+        link: Link::new(),
+    };
+
+    let mut list = circular::CircularList::<TestMember, TestAdapter>::new();
+    unittest::assert_true!(unsafe { list.cursor() }.current().is_none());
+
+    for element in elements.iter_mut() {
+        unsafe { list.push_back(element) };
+    }
+    unsafe { validate_circular_list(&list, &[1, 2, 3])? };
+
+    unsafe { list.push_front(&mut element0) };
+    unsafe { validate_circular_list(&list, &[0, 1, 2, 3])? };
+
+    let mut cursor = unsafe { list.cursor() };
+    for _ in 0..10 {
+        cursor.move_next();
+    }
+    unittest::assert_eq!(cursor.current().map(|element| element.value), Some(2));
+    Ok(())
+}
+
+#[test]
+fn circular_list_unlink_repairs_ring() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = circular::CircularList::<TestMember, TestAdapter>::new();
+    for element in elements.iter_mut() {
+        unsafe { list.push_back(element) };
+    }
+
+    let [element1, element2, element3, element4] = &elements;
+    unsafe { list.unlink_element(element2) };
+    unittest::assert_true!(element2.link.is_unlinked());
+    unsafe { validate_circular_list(&list, &[1, 3, 4])? };
+
+    unsafe { list.unlink_element(element1) };
+    unsafe { validate_circular_list(&list, &[3, 4])? };
+
+    unsafe { list.unlink_element(element4) };
+    unsafe { validate_circular_list(&list, &[3])? };
+
+    // Removing the only element empties the ring.
+    unsafe { list.unlink_element(element3) };
+    unittest::assert_true!(element3.link.is_unlinked());
+    unittest::assert_true!(unsafe { list.is_empty() });
+    unittest::assert_eq!(unsafe { list.len() }, 0);
+    unittest::assert_true!(unsafe { list.pop_head() }.is_none());
+    Ok(())
+}
+
+#[test]
+fn circular_list_pop_head_walks_ring() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = circular::CircularList::<TestMember, TestAdapter>::new();
+    for element in elements.iter_mut() {
+        unsafe { list.push_back(element) };
+    }
+
+    let mut index = 0;
+    unsafe {
+        list.for_each(|element| {
+            unittest::assert_eq!(element.value, [1, 2, 3][index]);
+            index += 1;
+            Ok(())
+        })?
+    };
+    unittest::assert_eq!(index, 3);
+
+    unittest::assert_eq!(
+        unsafe { list.pop_head() }.map(|element| element.value),
+        Some(1)
+    );
+    unsafe { validate_circular_list(&list, &[2, 3])? };
+    unittest::assert_eq!(
+        unsafe { list.pop_head() }.map(|element| element.value),
+        Some(2)
+    );
+    unittest::assert_eq!(
+        unsafe { list.pop_head() }.map(|element| element.value),
+        Some(3)
+    );
+    unittest::assert_true!(unsafe { list.pop_head() }.is_none());
+    Ok(())
+}
+
+#[test]
+fn merge_interleaves_sorted_lists() -> unittest::Result<()> {
+    let mut elements = [1, 3, 3, 7].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+    let mut other_elements = [0, 2, 3, 4, 8, 9].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+    let mut other = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { other.extend_back(other_elements.iter_mut()) };
+
+    unsafe { list.merge(&mut other, |a, b| a.value.cmp(&b.value)) };
+    unsafe { validate_list(&list, &[0, 1, 2, 3, 3, 3, 4, 7, 8, 9])? };
+    unsafe { validate_list(&other, &[])? };
+
+    // Elements of `self` come before equal elements of `other`.
+    let [_, first_three, second_three, _] = &elements;
+    let [_, _, other_three, ..] = &other_elements;
+    unittest::assert_eq!(unsafe { list.position(first_three) }, Some(3));
+    unittest::assert_eq!(unsafe { list.position(second_three) }, Some(4));
+    unittest::assert_eq!(unsafe { list.position(other_three) }, Some(5));
+    Ok(())
+}
+
+#[test]
+fn merge_into_empty_and_from_empty() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    let mut other = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { other.extend_back(elements.iter_mut()) };
+
+    unsafe { list.merge(&mut other, |a, b| a.value.cmp(&b.value)) };
+    unsafe { validate_list(&list, &[1, 2, 3])? };
+    unsafe { validate_list(&other, &[])? };
+
+    unsafe { list.merge(&mut other, |a, b| a.value.cmp(&b.value)) };
+    unsafe { validate_list(&list, &[1, 2, 3])? };
+    unsafe { validate_list(&other, &[]) }
+}
+
+#[test]
+fn nth_returns_element_at_index() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    unittest::assert_eq!(unsafe { list.nth(0) }.map(|element| element.value), Some(1));
+    unittest::assert_eq!(unsafe { list.nth(2) }.map(|element| element.value), Some(3));
+    unittest::assert_eq!(unsafe { list.nth(3) }.map(|element| element.value), Some(4));
+    unittest::assert_true!(unsafe { list.nth(4) }.is_none());
+    unittest::assert_true!(unsafe { list.nth(usize::MAX) }.is_none());
+    Ok(())
+}
+
+#[test]
+fn nth_mut_returns_element_at_index() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    if let Some(element) = unsafe { list.nth_mut(0) } {
+        element.value = 10;
+    }
+    if let Some(element) = unsafe { list.nth_mut(1) } {
+        element.value = 20;
+    }
+    if let Some(element) = unsafe { list.nth_mut(3) } {
+        element.value = 40;
+    }
+    unittest::assert_true!(unsafe { list.nth_mut(4) }.is_none());
+
+    unsafe { validate_list(&list, &[10, 20, 3, 40]) }?;
+    unittest::assert_eq!(
+        unsafe { list.pop_tail() }.map(|element| element.value),
+        Some(40)
+    );
+    unsafe { validate_list(&list, &[10, 20, 3]) }
+}
+
+#[test]
+fn insert_at_links_element_at_index() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4, 5].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+    let [element1, element2, element3, element4, element5] = &mut elements;
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unittest::assert_true!(unsafe { list.insert_at(0, element2) }.is_ok());
+    unsafe { validate_list(&list, &[2])? };
+
+    unittest::assert_true!(unsafe { list.insert_at(0, element1) }.is_ok());
+    unsafe { validate_list(&list, &[1, 2])? };
+
+    unittest::assert_true!(unsafe { list.insert_at(2, element4) }.is_ok());
+    unsafe { validate_list(&list, &[1, 2, 4])? };
+
+    unittest::assert_true!(unsafe { list.insert_at(2, element3) }.is_ok());
+    unsafe { validate_list(&list, &[1, 2, 3, 4])? };
+
+    // Past the end the element is handed back unlinked.
+    let rejected = unsafe { list.insert_at(5, element5) };
+    unittest::assert_eq!(rejected.map_err(|element| element.value), Err(5));
+    unittest::assert_true!(element5.link.is_unlinked());
+    unsafe { validate_list(&list, &[1, 2, 3, 4]) }
+}
+
+#[test]
+fn remove_at_unlinks_element_at_index() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4, 5].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    let head =
+        unsafe { list.remove_at(0) }.map(|element| (element.value, element.link.is_unlinked()));
+    unittest::assert_eq!(head, Some((1, true)));
+    unsafe { validate_list(&list, &[2, 3, 4, 5])? };
+
+    let tail =
+        unsafe { list.remove_at(3) }.map(|element| (element.value, element.link.is_unlinked()));
+    unittest::assert_eq!(tail, Some((5, true)));
+    unsafe { validate_list(&list, &[2, 3, 4])? };
+
+    let middle =
+        unsafe { list.remove_at(1) }.map(|element| (element.value, element.link.is_unlinked()));
+    unittest::assert_eq!(middle, Some((3, true)));
+    unsafe { validate_list(&list, &[2, 4])? };
+
+    unittest::assert_true!(unsafe { list.remove_at(2) }.is_none());
+    unsafe { validate_list(&list, &[2, 4]) }
+}
+
+#[test]
+fn max_len_tracks_high_water_mark() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4, 5].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unittest::assert_eq!(unsafe { list.max_len() }, 0);
+
+    for element in elements.iter_mut() {
+        unsafe { list.push_back_unchecked(element) };
+    }
+    unittest::assert_eq!(unsafe { list.max_len() }, 5);
+
+    for _ in 0..4 {
+        unsafe { list.pop_head() };
+    }
+    unittest::assert_eq!(unsafe { list.len() }, 1);
+    unittest::assert_eq!(unsafe { list.max_len() }, 5);
+
+    // Resetting starts a new interval at the current length.
+    unsafe { list.reset_stats() };
+    unittest::assert_eq!(unsafe { list.max_len() }, 1);
+
+    let [element1, element2, ..] = &mut elements;
+    unsafe { list.push_front_unchecked(element1) };
+    unsafe { list.push_front_unchecked(element2) };
+    unittest::assert_eq!(unsafe { list.max_len() }, 3);
+    Ok(())
+}
+
+#[test]
+fn checked_push_links_unlinked_elements() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    let [element1, element2, element3] = &mut elements;
+    unsafe { list.push_back(element2) };
+    unsafe { list.push_front(element1) };
+    unsafe { list.push_back(element3) };
+    unsafe { validate_list(&list, &[1, 2, 3])? };
+
+    // A popped element may be pushed again.
+    if let Some(element) = unsafe { list.pop_head() } {
+        unsafe { list.push_back(element) };
+    }
+    unsafe { validate_list(&list, &[2, 3, 1]) }
+}
+
+#[repr(C)]
+struct TwoListMember {
+    value: u32,
+    link_a: Link,
+    link_b: Link,
+}
+
+struct TwoListAdapterA {}
+unsafe impl Adapter for TwoListAdapterA {
+    const LINK_OFFSET: usize = offset_of!(TwoListMember, link_a);
+}
+
+struct TwoListAdapterB {}
+unsafe impl Adapter for TwoListAdapterB {
+    const LINK_OFFSET: usize = offset_of!(TwoListMember, link_b);
+}
+
+#[test]
+fn checked_push_allows_membership_through_another_link() -> unittest::Result<()> {
+    let mut elements = [1, 2].map(|value| TwoListMember {
+        value,
+        link_a: Link::new(),
+        link_b: Link::new(),
+    });
+
+    let mut list_a = UnsafeList::<TwoListMember, TwoListAdapterA>::new();
+    let mut list_b = UnsafeList::<TwoListMember, TwoListAdapterB>::new();
+    let [element1, element2] = &mut elements;
+    unsafe { list_a.push_back(element1) };
+    unsafe { list_a.push_back(element2) };
+
+    // Membership of `list_a` does not count against `list_b`, even for the
+    // debug-only walk, because only `link_b` is checked.
+    unsafe { list_b.push_back(element2) };
+    unsafe { list_b.push_front(element1) };
+
+    let mut iter = unsafe { list_b.iter() }.map(|element| element.value);
+    unittest::assert_eq!(iter.next(), Some(1));
+    unittest::assert_eq!(iter.next(), Some(2));
+    unittest::assert_true!(iter.next().is_none());
+    unittest::assert_eq!(unsafe { list_a.len() }, 2);
+    unittest::assert_eq!(unsafe { list_b.len() }, 2);
+    Ok(())
+}
+
+#[test]
+fn take_moves_all_elements() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    let taken = unsafe { list.take() };
+    unittest::assert_true!(unsafe { list.is_empty() });
+    unsafe { validate_list(&list, &[])? };
+    unsafe { validate_list(&taken, &[1, 2, 3])? };
+
+    let taken = unsafe { list.take() };
+    unsafe { validate_list(&taken, &[]) }
+}
+
+#[test]
+fn drain_sorted_into_returns_sorted_list() -> unittest::Result<()> {
+    let mut elements = [3, 1, 4, 1, 5, 2].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    let sorted = unsafe { list.drain_sorted_into(|a, b| a.value.cmp(&b.value)) };
+    unsafe { validate_list(&list, &[])? };
+    unsafe { validate_list(&sorted, &[1, 1, 2, 3, 4, 5]) }
+}
+
+#[test]
+fn for_each_allows_reentrant_reads() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    let mut index = 0;
+    unsafe {
+        list.for_each(|element| {
+            // Read-only methods on the list being walked see a consistent list.
+            unittest::assert_eq!(list.position(element), Some(index));
+            unittest::assert_eq!(list.len(), 3);
+            unittest::assert_true!(list.contains(element));
+            index += 1;
+            Ok(())
+        })?
+    };
+
+    unittest::assert_eq!(index, 3);
+    unsafe { validate_list(&list, &[1, 2, 3]) }
+}
+
+#[test]
+fn for_each_mut_mutates_every_element() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    unsafe {
+        list.for_each_mut(|element| {
+            element.value *= 10;
+            Ok(())
+        })?
+    };
+
+    unsafe { validate_list(&list, &[10, 20, 30]) }
+}
+
+#[test]
+fn for_each_mut_stops_at_first_error() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    let mut visited = 0;
+    let result = unsafe {
+        list.for_each_mut(|element| {
+            visited += 1;
+            if element.value == 2 {
+                return Err(element.value);
+            }
+            element.value *= 10;
+            Ok(())
+        })
+    };
+
+    unittest::assert_eq!(result, Err(2));
+    unittest::assert_eq!(visited, 2);
+    unsafe { validate_list(&list, &[10, 2, 3]) }?;
+
+    // The written element can still be unlinked through the list.
+    unittest::assert_eq!(
+        unsafe { list.pop_head() }.map(|element| element.value),
+        Some(10)
+    );
+    unsafe { validate_list(&list, &[2, 3]) }
+}
+
+#[test]
+fn for_each_removable_survives_moving_current_element() -> unittest::Result<()> {
+    let mut elements = [2, 1, 4, 3, 6].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+    let mut other = UnsafeList::<TestMember, TestAdapter>::new();
+
+    // The head, a middle element and the tail are all moved out from under
+    // the walk.
+    let mut visited = 0;
+    unsafe {
+        list.for_each_removable(|list, element| {
+            visited += 1;
+            if element.value % 2 == 0 {
+                list.unlink_element(element);
+                other.push_back(element);
+            }
+        })
+    };
+
+    unittest::assert_eq!(visited, 5);
+    unsafe { validate_list(&list, &[1, 3])? };
+    unsafe { validate_list(&other, &[2, 4, 6]) }
+}
+
+#[test]
+fn for_each_while_stops_at_first_false() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4, 5].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    let mut visited = [0; 5];
+    let mut visited_count = 0;
+    unsafe {
+        list.for_each_while(|element| {
+            visited[visited_count] = element.value;
+            visited_count += 1;
+            element.value < 3
+        })
+    };
+
+    // The element that stopped the walk is visited, the rest are not.
+    unittest::assert_eq!(&visited[..visited_count], &[1, 2, 3]);
+
+    let mut visited_count = 0;
+    unsafe {
+        list.for_each_while(|_| {
+            visited_count += 1;
+            true
+        })
+    };
+    unittest::assert_eq!(visited_count, 5);
+    Ok(())
+}
+
+#[test]
+fn for_each_pair_visits_adjacent_elements_in_order() -> unittest::Result<()> {
+    let mut builder = pin!(ListBuilder::<4>::new());
+    builder.as_mut().push(1).push(2).push(3).push(4);
+    let list = builder.as_mut().list_mut();
+
+    let mut pairs = [(0, 0); 4];
+    let mut pair_count = 0;
+    unsafe {
+        list.for_each_pair(|current, next| {
+            pairs[pair_count] = (current.value, next.value);
+            pair_count += 1;
+        })
+    };
+    unittest::assert_eq!(&pairs[..pair_count], &[(1, 2), (2, 3), (3, 4)]);
+
+    while unsafe { list.len() } > 1 {
+        unsafe { list.pop_tail() };
+    }
+    let mut pair_count = 0;
+    unsafe { list.for_each_pair(|_, _| pair_count += 1) };
+    unittest::assert_eq!(pair_count, 0);
+    Ok(())
+}
+
+#[test]
+fn iter_rev_yields_elements_in_reverse_order() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unittest::assert_true!(unsafe { list.iter_rev() }.next().is_none());
+
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    let mut iter = unsafe { list.iter_rev() };
+    unittest::assert_eq!(iter.next().map(|element| element.value), Some(3));
+    unittest::assert_eq!(iter.next().map(|element| element.value), Some(2));
+    unittest::assert_eq!(iter.next().map(|element| element.value), Some(1));
+    unittest::assert_true!(iter.next().is_none());
+    Ok(())
+}
+
+#[test]
+fn iter_next_back_yields_elements_in_reverse_order() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unittest::assert_true!(unsafe { list.iter() }.next_back().is_none());
+
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    let mut iter = unsafe { list.iter() }.rev();
+    unittest::assert_eq!(iter.next().map(|element| element.value), Some(3));
+    unittest::assert_eq!(iter.next().map(|element| element.value), Some(2));
+    unittest::assert_eq!(iter.next().map(|element| element.value), Some(1));
+    unittest::assert_true!(iter.next().is_none());
+    Ok(())
+}
+
+#[test]
+fn iter_from_both_ends_meets_on_odd_length() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4, 5].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    let mut iter = unsafe { list.iter() };
+    unittest::assert_eq!(iter.next().map(|element| element.value), Some(1));
+    unittest::assert_eq!(iter.next_back().map(|element| element.value), Some(5));
+    unittest::assert_eq!(iter.next_back().map(|element| element.value), Some(4));
+    unittest::assert_eq!(iter.next().map(|element| element.value), Some(2));
+    unittest::assert_eq!(iter.next_back().map(|element| element.value), Some(3));
+    unittest::assert_true!(iter.next().is_none());
+    unittest::assert_true!(iter.next_back().is_none());
+    Ok(())
+}
+
+#[test]
+fn iter_from_both_ends_crosses_on_even_length() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    let mut iter = unsafe { list.iter() };
+    unittest::assert_eq!(iter.next_back().map(|element| element.value), Some(4));
+    unittest::assert_eq!(iter.next().map(|element| element.value), Some(1));
+    unittest::assert_eq!(iter.next().map(|element| element.value), Some(2));
+    unittest::assert_eq!(iter.next_back().map(|element| element.value), Some(3));
+    unittest::assert_true!(iter.next_back().is_none());
+    unittest::assert_true!(iter.next().is_none());
+
+    // A single element is yielded only once.
+    let mut iter = unsafe { list.iter() };
+    iter.next();
+    iter.next();
+    iter.next();
+    unittest::assert_eq!(iter.next_back().map(|element| element.value), Some(4));
+    unittest::assert_true!(iter.next().is_none());
+    Ok(())
+}
+
+#[test]
+fn iter_from_starts_at_member() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4, 5].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+    let [element1, _, element3, _, element5] = &elements;
+
+    let iter = unsafe { list.iter_from(element3) };
+    unittest::assert_eq!(iter.len(), 3);
+    let mut iter = iter.map(|element| element.value);
+    unittest::assert_eq!(iter.next(), Some(3));
+    unittest::assert_eq!(iter.next(), Some(4));
+    unittest::assert_eq!(iter.next(), Some(5));
+    unittest::assert_true!(iter.next().is_none());
+
+    // Iterating back from the tail stops at `start`.
+    let mut iter = unsafe { list.iter_from(element3) }.map(|element| element.value);
+    unittest::assert_eq!(iter.next_back(), Some(5));
+    unittest::assert_eq!(iter.next_back(), Some(4));
+    unittest::assert_eq!(iter.next_back(), Some(3));
+    unittest::assert_true!(iter.next_back().is_none());
+
+    unittest::assert_eq!(unsafe { list.iter_from(element1) }.len(), 5);
+    unittest::assert_eq!(unsafe { list.iter_from(element5) }.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn count_where_counts_matching_elements() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4, 5].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unittest::assert_eq!(unsafe { list.count_where(|_| true) }, 0);
+
+    unsafe { list.extend_back(elements.iter_mut()) };
+    unittest::assert_eq!(
+        unsafe { list.count_where(|element| element.value % 2 == 1) },
+        3
+    );
+    unittest::assert_eq!(unsafe { list.count_where(|_| true) }, 5);
+    unittest::assert_eq!(unsafe { list.count_where(|_| false) }, 0);
+    Ok(())
+}
+
+#[test]
+fn has_at_least_counts_up_to_k() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unittest::assert_true!(unsafe { list.has_at_least(0) });
+    unittest::assert_false!(unsafe { list.has_at_least(1) });
+
+    unsafe { list.extend_back(elements.iter_mut()) };
+    unittest::assert_true!(unsafe { list.has_at_least(4) });
+    unittest::assert_false!(unsafe { list.has_at_least(5) });
+
+    // Link the third and fourth nodes into a ring, so that a walk reaches the
+    // ring after the third node and never reaches the end of the list.  Only
+    // a walk bounded by `k` returns, even for a `k` larger than the number of
+    // nodes.  A regression to a full walk makes this test hang rather than
+    // fail.
+    let [_, _, element3, element4] = &mut elements;
+    unsafe {
+        Link::link_between(
+            Some(link_ptr(element4)),
+            link_ptr(element3),
+            Some(link_ptr(element4)),
+        )
+    };
+    unittest::assert_true!(unsafe { list.has_at_least(3) });
+    unittest::assert_true!(unsafe { list.has_at_least(6) });
+    Ok(())
+}
+
+struct OutOfBoundsAdapter {}
+
+unsafe impl Adapter for OutOfBoundsAdapter {
+    const LINK_OFFSET: usize = core::mem::size_of::<TestMember>();
+    const ADAPTER_TYPE_SIZE: usize = core::mem::size_of::<TestMember>();
+}
+
+struct MisalignedAdapter {}
+
+unsafe impl Adapter for MisalignedAdapter {
+    const LINK_OFFSET: usize = offset_of!(TestMember, link) + 1;
+}
+
+struct OverflowingAdapter {}
+
+unsafe impl Adapter for OverflowingAdapter {
+    const LINK_OFFSET: usize = usize::MAX;
+}
+
+const _: () = assert!(verify_layout::<TestMember, TestAdapter>());
+
+#[test]
+fn verify_layout_rejects_bad_offsets() -> unittest::Result<()> {
+    unittest::assert_true!(verify_layout::<TestMember, TestAdapter>());
+    unittest::assert_true!(verify_layout::<DerivedMember, DerivedMember>());
+    unittest::assert_false!(verify_layout::<TestMember, OutOfBoundsAdapter>());
+    unittest::assert_false!(verify_layout::<TestMember, MisalignedAdapter>());
+    unittest::assert_false!(verify_layout::<TestMember, OverflowingAdapter>());
+
+    unittest::assert_true!(verify_adapter::<TestAdapter>());
+    unittest::assert_true!(verify_adapter::<DerivedMember>());
+    unittest::assert_false!(verify_adapter::<OutOfBoundsAdapter>());
+    unittest::assert_false!(verify_adapter::<OverflowingAdapter>());
+    Ok(())
+}
+
+#[test]
+fn replace_keeps_position() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+    let mut replacements = [10, 20, 30].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    let [element1, element2, element3] = &elements;
+    let [element10, element20, element30] = &mut replacements;
+
+    unsafe { list.replace(element2, element20) };
+    unittest::assert_true!(element2.link.is_unlinked());
+    unsafe { validate_list(&list, &[1, 20, 3])? };
+
+    unsafe { list.replace(element1, element10) };
+    unittest::assert_true!(element1.link.is_unlinked());
+    unsafe { validate_list(&list, &[10, 20, 3])? };
+
+    unsafe { list.replace(element3, element30) };
+    unittest::assert_true!(element3.link.is_unlinked());
+    unsafe { validate_list(&list, &[10, 20, 30]) }
+}
+
+#[test]
+fn replace_sole_member() -> unittest::Result<()> {
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+    let mut element2 = TestMember {
+        value: 2,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unsafe { list.replace(&element1, &mut element2) };
+
+    unittest::assert_true!(element1.link.is_unlinked());
+    unsafe { validate_list(&list, &[2]) }
+}
+
+#[test]
+fn is_sorted_by_detects_inversion() -> unittest::Result<()> {
+    let mut sorted = [1, 2, 2, 3].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+    let mut unsorted = [1, 3, 2, 4].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+    let mut element1 = TestMember {
+        value: 1,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unittest::assert_true!(unsafe { list.is_sorted_by(|a, b| a.value.cmp(&b.value)) });
+
+    unsafe { list.push_back_unchecked(&mut element1) };
+    unittest::assert_true!(unsafe { list.is_sorted_by(|a, b| a.value.cmp(&b.value)) });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(sorted.iter_mut()) };
+    unittest::assert_true!(unsafe { list.is_sorted_by(|a, b| a.value.cmp(&b.value)) });
+    unittest::assert_false!(unsafe { list.is_sorted_by(|a, b| b.value.cmp(&a.value)) });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(unsorted.iter_mut()) };
+    unittest::assert_false!(unsafe { list.is_sorted_by(|a, b| a.value.cmp(&b.value)) });
+    Ok(())
+}
+
+#[test]
+fn from_raw_adopts_existing_chain() -> unittest::Result<()> {
+    let mut elements = [1, 2].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let elements_ptr = elements.as_mut_ptr();
+
+    // Link the elements through a list header that is then discarded,
+    // leaving only the chain of links behind.
+    {
+        let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+        unsafe { list.push_back_unchecked(&mut *elements_ptr) };
+        unsafe { list.push_back_unchecked(&mut *elements_ptr.add(1)) };
+    }
+
+    let list = unsafe {
+        UnsafeList::<TestMember, TestAdapter>::from_raw(
+            Some(link_ptr(elements_ptr)),
+            Some(link_ptr(elements_ptr.add(1))),
+        )
+    };
+    unsafe { validate_list(&list, &[1, 2])? };
+
+    let empty = unsafe { UnsafeList::<TestMember, TestAdapter>::from_raw(None, None) };
+    unsafe { validate_list(&empty, &[]) }
+}
+
+#[test]
+fn link_between_builds_chain_without_list() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+    let elements_ptr = elements.as_mut_ptr();
+    let link1 = unsafe { link_ptr(elements_ptr) };
+    let link2 = unsafe { link_ptr(elements_ptr.add(1)) };
+    let link3 = unsafe { link_ptr(elements_ptr.add(2)) };
+    let [element1, element2, _] = &elements;
+
+    // Link the ends first, then splice the middle in between them.
+    unsafe { Link::link_between(None, link1, None) };
+    unittest::assert_true!(element1.link.is_unlinked());
+    unsafe { Link::link_between(Some(link1), link3, None) };
+    unsafe { Link::link_between(Some(link1), link2, Some(link3)) };
+    unittest::assert_true!(element2.link.is_linked());
+
+    let list = unsafe { UnsafeList::<TestMember, TestAdapter>::from_raw(Some(link1), Some(link3)) };
+    unsafe { validate_list(&list, &[1, 2, 3]) }
+}
+
+// Written against `ListLike` only, the way code in an abstraction layer
+// would be.
+unsafe fn refill<L: ListLike<TestMember>>(list: &mut L, elements: &mut [TestMember]) -> u32 {
+    list.reserve(elements.len());
+    for element in elements {
+        list.push_back(element);
+    }
+    list.shrink_to_fit();
+
+    let mut sum = 0;
+    while let Some(element) = list.pop_head() {
+        sum += element.value;
+    }
+    sum
+}
+
+#[test]
+fn list_like_is_usable_generically() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unittest::assert_true!(unsafe { ListLike::is_compact(&list) });
+
+    let sum = unsafe { refill(&mut list, &mut elements) };
+    unittest::assert_eq!(sum, 6);
+    unittest::assert_true!(unsafe { ListLike::is_empty(&list) });
+    unittest::assert_true!(unsafe { ListLike::is_compact(&list) });
+    unsafe { validate_list(&list, &[]) }
+}
+
+#[test]
+fn move_matching_to_moves_subset_in_order() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4, 5, 6].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+    let mut element0 = TestMember {
+        value: 0,
+        link: Link::new(),
+    };
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+    let mut dest = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { dest.push_back_unchecked(&mut element0) };
+
+    unsafe { list.move_matching_to(&mut dest, |element| element.value % 2 == 0) };
+    unsafe { validate_list(&list, &[1, 3, 5])? };
+    unsafe { validate_list(&dest, &[0, 2, 4, 6])? };
+
+    unsafe { list.move_matching_to(&mut dest, |_| false) };
+    unsafe { validate_list(&list, &[1, 3, 5])? };
+
+    unsafe { list.move_matching_to(&mut dest, |_| true) };
+    unsafe { validate_list(&list, &[])? };
+    unsafe { validate_list(&dest, &[0, 2, 4, 6, 1, 3, 5]) }
+}
+
+#[test]
+fn swap_lists_exchanges_contents() -> unittest::Result<()> {
+    let mut elements = [1, 2].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+    let mut other_elements = [3, 4, 5].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+    let mut other = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { other.extend_back(other_elements.iter_mut()) };
+
+    unsafe { list.swap_lists(&mut other) };
+    unsafe { validate_list(&list, &[3, 4, 5])? };
+    unsafe { validate_list(&other, &[1, 2])? };
+    unittest::assert_eq!(unsafe { list.max_len() }, 3);
+    unittest::assert_eq!(unsafe { other.max_len() }, 2);
+
+    let mut empty = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.swap_lists(&mut empty) };
+    unsafe { validate_list(&list, &[])? };
+    unsafe { validate_list(&empty, &[3, 4, 5]) }
+}
+
+#[test]
+fn unlink_self_detaches_interior_member() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    // `for_each_mut` caches the next element before calling back, so an
+    // interior member can remove itself.
+    let mut removed = 0;
+    unsafe {
+        list.for_each_mut(|element| {
+            if element.value == 2 || element.value == 3 {
+                element.link.unlink_self();
+                unittest::assert_true!(element.link.is_unlinked());
+                removed += 1;
+            }
+            Ok(())
+        })?
+    };
+    unittest::assert_eq!(removed, 2);
+
+    // The length is only repaired once the list is told about the removals.
+    unittest::assert_false!(unsafe { list.is_valid() });
+    for _ in 0..removed {
+        unsafe { list.note_unlinked() };
+    }
+    unsafe { validate_list(&list, &[1, 4])? };
+
+    let mut iter = unsafe { list.iter_rev() };
+    unittest::assert_eq!(iter.next().map(|element| element.value), Some(4));
+    unittest::assert_eq!(iter.next().map(|element| element.value), Some(1));
+    unittest::assert_true!(iter.next().is_none());
+    Ok(())
+}
+
+#[test]
+fn unlink_self_on_unlinked_link_does_nothing() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+    unsafe { list.unlink_element(&elements[1]) };
+
+    // In debug builds the unlinked link is poisoned, which must not be
+    // followed.
+    unsafe { elements[1].link.unlink_self() };
+    unittest::assert_true!(elements[1].link.is_unlinked());
+    unsafe { validate_list(&list, &[1, 3])? };
+
+    // A link that was never linked has no neighbors either.
+    let mut unlinked = TestMember {
+        value: 4,
+        link: Link::new(),
+    };
+    unsafe { unlinked.link.unlink_self() };
+    unittest::assert_true!(unlinked.link.is_unlinked());
+    Ok(())
+}
+
+#[test]
+fn snapshot_iterates_same_elements() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    let snapshot = unsafe { list.snapshot() };
+    unsafe { validate_list(&snapshot, &[1, 2, 3])? };
+    unittest::assert_true!(unsafe { list.iter() }
+        .zip(unsafe { snapshot.iter() })
+        .all(|(a, b)| core::ptr::eq(a, b)));
+    unsafe { validate_list(&list, &[1, 2, 3]) }
+}
+
+#[test]
+fn dedup_by_removes_consecutive_duplicates() -> unittest::Result<()> {
+    let mut elements = [1, 1, 2, 3, 3, 3, 4, 1].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unittest::assert_eq!(unsafe { list.dedup_by(|a, b| a.value == b.value) }, 0);
+
+    unsafe { list.extend_back(elements.iter_mut()) };
+    unittest::assert_eq!(unsafe { list.dedup_by(|a, b| a.value == b.value) }, 3);
+    unsafe { validate_list(&list, &[1, 2, 3, 4, 1])? };
+
+    // The first element of each run is kept.
+    let [element1, element1_dup, _, element3, element3_dup, element3_dup2, _, _] = &elements;
+    unittest::assert_true!(element1.link.is_linked());
+    unittest::assert_true!(element3.link.is_linked());
+    unittest::assert_true!(element1_dup.link.is_unlinked());
+    unittest::assert_true!(element3_dup.link.is_unlinked());
+    unittest::assert_true!(element3_dup2.link.is_unlinked());
+    Ok(())
+}
+
+#[test]
+fn partition_splits_by_predicate() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4, 5].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    let rejected = unsafe { list.partition(|_| true) };
+    unsafe { validate_list(&list, &[1, 2, 3, 4, 5])? };
+    unsafe { validate_list(&rejected, &[])? };
+
+    let mut odd = unsafe { list.partition(|element| element.value % 2 == 0) };
+    unsafe { validate_list(&list, &[2, 4])? };
+    unsafe { validate_list(&odd, &[1, 3, 5])? };
+
+    let rejected = unsafe { odd.partition(|_| false) };
+    unsafe { validate_list(&odd, &[])? };
+    unsafe { validate_list(&rejected, &[1, 3, 5]) }
+}
+
+#[test]
+fn bounded_list_rejects_push_past_capacity() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = bounded::BoundedList::<TestMember, TestAdapter, 3>::new();
+    unittest::assert_eq!(list.capacity(), 3);
+
+    let [element1, element2, element3, element4] = &mut elements;
+    unittest::assert_true!(unsafe { list.push_back(element2) }.is_ok());
+    unittest::assert_true!(unsafe { list.push_front(element1) }.is_ok());
+    unittest::assert_true!(unsafe { list.push_back(element3) }.is_ok());
+    unittest::assert_true!(unsafe { list.is_full() });
+
+    let rejected = unsafe { list.push_back(element4) };
+    unittest::assert_eq!(rejected.map_err(|element| element.value), Err(4));
+    unittest::assert_true!(element4.link.is_unlinked());
+    unittest::assert_true!(unsafe { list.push_front(element4) }.is_err());
+    unsafe { validate_list(list.as_list(), &[1, 2, 3])? };
+
+    // Popping makes room again.
+    unsafe { list.pop_head() };
+    unittest::assert_true!(unsafe { list.push_back(element4) }.is_ok());
+    unsafe { validate_list(list.as_list(), &[2, 3, 4]) }
+}
+
+#[test]
+fn monitored_list_mirrors_len() -> unittest::Result<()> {
+    // Compile-time check: the header can be shared with a monitoring thread.
+    assert_sync::<monitored::MonitoredList<TestMember, TestAdapter>>();
+
+    let mut elements = [1, 2, 3].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+    let [element1, element2, element3] = &mut elements;
+
+    let list = monitored::MonitoredList::<TestMember, TestAdapter>::new();
+    unittest::assert_eq!(list.len_relaxed(), 0);
+
+    unsafe { list.push_back(element2) };
+    unsafe { list.push_front(element1) };
+    unsafe { list.push_back(element3) };
+    unittest::assert_eq!(list.len_relaxed(), 3);
+    unsafe { validate_list(list.as_list(), &[1, 2, 3])? };
+
+    unsafe { list.unlink_element(element2) };
+    unittest::assert_eq!(list.len_relaxed(), 2);
+
+    unittest::assert_eq!(
+        unsafe { list.pop_tail() }.map(|element| element.value),
+        Some(3)
+    );
+    unittest::assert_eq!(
+        unsafe { list.pop_head() }.map(|element| element.value),
+        Some(1)
+    );
+    unittest::assert_true!(unsafe { list.pop_head() }.is_none());
+    unittest::assert_eq!(list.len_relaxed(), 0);
+    unittest::assert_true!(unsafe { list.is_empty() });
+    Ok(())
+}
+
+struct FixedBuffer {
+    buffer: [u8; 32],
+    len: usize,
+}
+
+impl FixedBuffer {
+    fn new() -> Self {
+        Self {
+            buffer: [0; 32],
+            len: 0,
+        }
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        &self.buffer[..self.len]
+    }
+}
+
+impl core::fmt::Write for FixedBuffer {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        if end > self.buffer.len() {
+            return Err(core::fmt::Error);
+        }
+        self.buffer[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+struct ListDump<'a>(&'a UnsafeList<TestMember, TestAdapter>);
+
+impl core::fmt::Display for ListDump<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        unsafe {
+            self.0
+                .debug_fmt(f, |element, f| write!(f, "{}", element.value))
+        }
+    }
+}
+
+#[test]
+fn debug_fmt_writes_elements() -> unittest::Result<()> {
+    use core::fmt::Write;
+
+    let mut elements = [1, 22, 333].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    let mut buffer = FixedBuffer::new();
+    unittest::assert_true!(write!(buffer, "{}", ListDump(&list)).is_ok());
+    unittest::assert_eq!(buffer.as_bytes(), b"[]");
+
+    unsafe { list.extend_back(elements.iter_mut()) };
+    let mut buffer = FixedBuffer::new();
+    unittest::assert_true!(write!(buffer, "{}", ListDump(&list)).is_ok());
+    unittest::assert_eq!(buffer.as_bytes(), b"[1, 22, 333]");
+    Ok(())
+}
+
+#[test]
+fn rotate_to_makes_element_head() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4, 5].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    let [element1, _, element3, _, element5] = &elements;
+    unsafe { list.rotate_to(element1) };
+    unsafe { validate_list(&list, &[1, 2, 3, 4, 5])? };
+
+    unsafe { list.rotate_to(element3) };
+    unsafe { validate_list(&list, &[3, 4, 5, 1, 2])? };
+
+    unsafe { list.rotate_to(element5) };
+    unsafe { validate_list(&list, &[5, 1, 2, 3, 4]) }
+}
+
+#[test]
+fn rotate_until_stops_at_first_match() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4, 5].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    let mut calls = 0;
+    let found = unsafe {
+        list.rotate_until(|element| {
+            calls += 1;
+            element.value >= 3
+        })
+    };
+    unittest::assert_true!(found);
+    unittest::assert_eq!(calls, 3);
+    unsafe { validate_list(&list, &[3, 4, 5, 1, 2])? };
+
+    // A predicate matching nothing visits every element once and leaves the
+    // order untouched.
+    let mut calls = 0;
+    let found = unsafe {
+        list.rotate_until(|_| {
+            calls += 1;
+            false
+        })
+    };
+    unittest::assert_false!(found);
+    unittest::assert_eq!(calls, 5);
+    unsafe { validate_list(&list, &[3, 4, 5, 1, 2]) }
+}
+
+#[test]
+fn is_singleton_only_for_one_member() -> unittest::Result<()> {
+    let mut elements = [1, 2].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unittest::assert_false!(unsafe { list.is_singleton() });
+
+    let [element1, element2] = &mut elements;
+    unsafe { list.push_back_unchecked(element1) };
+    unittest::assert_true!(unsafe { list.is_singleton() });
+
+    unsafe { list.push_back_unchecked(element2) };
+    unittest::assert_false!(unsafe { list.is_singleton() });
+    Ok(())
+}
+
+#[test]
+fn next_of_and_prev_of_navigate_from_member() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    let [element1, element2, element3] = &elements;
+    unittest::assert_eq!(
+        unsafe { list.next_of(element1) }.map(|element| element.value),
+        Some(2)
+    );
+    unittest::assert_eq!(
+        unsafe { list.next_of(element2) }.map(|element| element.value),
+        Some(3)
+    );
+    unittest::assert_true!(unsafe { list.next_of(element3) }.is_none());
+
+    unittest::assert_eq!(
+        unsafe { list.prev_of(element3) }.map(|element| element.value),
+        Some(2)
+    );
+    unittest::assert_eq!(
+        unsafe { list.prev_of(element2) }.map(|element| element.value),
+        Some(1)
+    );
+    unittest::assert_true!(unsafe { list.prev_of(element1) }.is_none());
+    Ok(())
+}
+
+#[cfg(feature = "defmt")]
+mod defmt_logger {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    pub static BYTES_WRITTEN: AtomicUsize = AtomicUsize::new(0);
+
+    // Host builds don't use defmt's linker script, which would otherwise
+    // provide an empty timestamp.
+    defmt::timestamp!("");
+
+    #[defmt::global_logger]
+    struct CountingLogger;
+
+    unsafe impl defmt::Logger for CountingLogger {
+        fn acquire() {}
+
+        unsafe fn flush() {}
+
+        unsafe fn release() {}
+
+        unsafe fn write(bytes: &[u8]) {
+            BYTES_WRITTEN.fetch_add(bytes.len(), Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[test]
+fn defmt_format_logs_list_header() -> unittest::Result<()> {
+    use core::sync::atomic::Ordering;
+
+    let mut elements = [1, 2].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    let before = defmt_logger::BYTES_WRITTEN.load(Ordering::Relaxed);
+    defmt::println!("{}", list);
+    unittest::assert_true!(defmt_logger::BYTES_WRITTEN.load(Ordering::Relaxed) > before);
+    Ok(())
+}
+
+#[test]
+fn cursor_splice_after_inserts_list() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+    let mut middle = [10, 11].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+    let mut back = [20, 21].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+    let mut other = UnsafeList::<TestMember, TestAdapter>::new();
+
+    {
+        let mut cursor = unsafe { list.cursor_front_mut() };
+
+        // Splicing into the middle.
+        unsafe { other.extend_back(middle.iter_mut()) };
+        unsafe { cursor.splice_after(&mut other) };
+        unittest::assert_eq!(cursor.current().map(|element| element.value), Some(1));
+        unsafe { validate_list(&other, &[])? };
+
+        // Splicing after the tail.
+        cursor.move_prev();
+        cursor.move_prev();
+        unittest::assert_eq!(cursor.current().map(|element| element.value), Some(3));
+        unsafe { other.extend_back(back.iter_mut()) };
+        unsafe { cursor.splice_after(&mut other) };
+
+        // Splicing an empty list does nothing.
+        unsafe { cursor.splice_after(&mut other) };
+    }
+
+    unsafe { validate_list(&list, &[1, 10, 11, 2, 3, 20, 21]) }
+}
+
+#[test]
+fn cursor_splice_after_into_empty_list() -> unittest::Result<()> {
+    let mut elements = [1, 2].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    let mut other = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { other.extend_back(elements.iter_mut()) };
+
+    {
+        let mut cursor = unsafe { list.cursor_front_mut() };
+        unsafe { cursor.splice_after(&mut other) };
+        unittest::assert_true!(cursor.current().is_none());
+    }
+
+    unsafe { validate_list(&list, &[1, 2])? };
+    unsafe { validate_list(&other, &[]) }
+}
+
+#[test]
+fn splice_before_inserts_list() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+    let mut front = [10, 11].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+    let mut middle = [20, 21].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+    let mut other = UnsafeList::<TestMember, TestAdapter>::new();
+    let [element1, element2, _] = &elements;
+
+    // Splicing before the head moves the head.
+    unsafe { other.extend_back(front.iter_mut()) };
+    unsafe { list.splice_before(element1, &mut other) };
+    unittest::assert_eq!(
+        unsafe { list.peek_head() }.map(|element| element.value),
+        Some(10)
+    );
+    unsafe { validate_list(&other, &[])? };
+    unsafe { validate_list(&list, &[10, 11, 1, 2, 3])? };
+
+    unsafe { other.extend_back(middle.iter_mut()) };
+    unsafe { list.splice_before(element2, &mut other) };
+    unsafe { validate_list(&list, &[10, 11, 1, 20, 21, 2, 3])? };
+
+    // Splicing an empty list does nothing.
+    unsafe { list.splice_before(element2, &mut other) };
+    unsafe { validate_list(&list, &[10, 11, 1, 20, 21, 2, 3]) }
+}
+
+#[test]
+fn retain_with_hands_removed_elements_to_sink() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4, 5].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    let mut removed = [0; 5];
+    let mut removed_count = 0;
+    let mut all_unlinked = true;
+    unsafe {
+        list.retain_with(
+            |element| element.value % 2 == 1,
+            |element| {
+                all_unlinked &= element.link.is_unlinked();
+                removed[removed_count] = element.value;
+                removed_count += 1;
+            },
+        )
+    };
+
+    unittest::assert_true!(all_unlinked);
+    unittest::assert_eq!(&removed[..removed_count], &[2, 4]);
+    unsafe { validate_list(&list, &[1, 3, 5]) }
+}
+
+#[test]
+fn filter_from_skips_elements_before_start() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4, 5, 6].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+    let [_, _, _, element4, _, _] = &elements;
+
+    let mut visited = [0; 6];
+    let mut visited_count = 0;
+    unsafe {
+        list.filter_from(element4, |element| {
+            visited[visited_count] = element.value;
+            visited_count += 1;
+            element.value % 2 == 1
+        })
+    };
+
+    // Even elements before `element4` are kept because they are never seen.
+    unittest::assert_eq!(&visited[..visited_count], &[4, 5, 6]);
+    unsafe { validate_list(&list, &[1, 2, 3, 5]) }
+}
+
+#[test]
+fn pinned_list_pushes_and_pops_pinned_elements() -> unittest::Result<()> {
+    let mut element1 = core::pin::pin!(TestMember {
+        value: 1,
+        link: Link::new(),
+    });
+    let element2 = core::pin::pin!(TestMember {
+        value: 2,
+        link: Link::new(),
+    });
+
+    {
+        let mut list = safe::PinnedList::<TestMember, TestAdapter>::new();
+        unittest::assert_true!(list.is_empty());
+
+        list.push_back(element2);
+        list.push_front(element1.as_mut());
+        unittest::assert_eq!(list.len(), 2);
+
+        let value = list.pop_head().map(|element| element.value);
+        unittest::assert_eq!(value, Some(1));
+        unittest::assert_eq!(list.len(), 1);
+    }
+
+    // Popping `element1` cleared its links.
+    unittest::assert_true!(element1.link.is_unlinked());
+    Ok(())
+}
+
+#[test]
+fn concat_all_appends_lists_in_order() -> unittest::Result<()> {
+    let mut first = [1, 2].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+    let mut third = [3, 4, 5].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut lists = [
+        UnsafeList::<TestMember, TestAdapter>::new(),
+        UnsafeList::new(),
+        UnsafeList::new(),
+    ];
+    unsafe { lists[0].extend_back(first.iter_mut()) };
+    unsafe { lists[2].extend_back(third.iter_mut()) };
+
+    let combined = unsafe { UnsafeList::concat_all(&mut lists) };
+    unsafe { validate_list(&combined, &[1, 2, 3, 4, 5])? };
+    for list in &lists {
+        unsafe { validate_list(list, &[])? };
+    }
+
+    let empty = unsafe { UnsafeList::<TestMember, TestAdapter>::concat_all(&mut []) };
+    unsafe { validate_list(&empty, &[]) }
+}
+
+#[test]
+fn chain_iterates_lists_in_sequence() -> unittest::Result<()> {
+    let mut front = [1, 2].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+    let mut back = [3, 4, 5].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list1 = UnsafeList::<TestMember, TestAdapter>::new();
+    let empty = UnsafeList::<TestMember, TestAdapter>::new();
+    let mut list3 = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list1.extend_back(front.iter_mut()) };
+    unsafe { list3.extend_back(back.iter_mut()) };
+
+    let lists = [&empty, &list1, &empty, &list3, &empty];
+    let mut iter = unsafe { UnsafeList::chain(&lists) }.map(|element| element.value);
+    for expected in 1..=5 {
+        unittest::assert_eq!(iter.next(), Some(expected));
+    }
+    unittest::assert_true!(iter.next().is_none());
+
+    // The lists themselves are untouched.
+    unsafe { validate_list(&list1, &[1, 2])? };
+    unsafe { validate_list(&list3, &[3, 4, 5]) }
+}
+
+#[test]
+fn is_element_linked_tracks_membership() -> unittest::Result<()> {
+    let mut elements = [1, 2].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    let [element1, element2] = &elements;
+    unittest::assert_true!(unsafe {
+        UnsafeList::<TestMember, TestAdapter>::is_element_linked(element1)
+    });
+    unittest::assert_true!(unsafe {
+        UnsafeList::<TestMember, TestAdapter>::is_element_linked(element2)
+    });
+
+    unsafe { list.unlink_element(element1) };
+    unittest::assert_false!(unsafe {
+        UnsafeList::<TestMember, TestAdapter>::is_element_linked(element1)
+    });
+
+    // The sole remaining member has no neighbors.
+    unittest::assert_false!(unsafe {
+        UnsafeList::<TestMember, TestAdapter>::is_element_linked(element2)
+    });
+    unittest::assert_true!(unsafe { list.contains(element2) });
+    Ok(())
+}
+
+#[test]
+fn fold_threads_accumulator() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unittest::assert_eq!(
+        unsafe { list.fold(7, |acc, element| acc + element.value) },
+        7
+    );
+
+    unsafe { list.extend_back(elements.iter_mut()) };
+    unittest::assert_eq!(
+        unsafe { list.fold(0, |acc, element| acc + element.value) },
+        10
+    );
+    unittest::assert_eq!(unsafe { list.fold(0usize, |count, _| count + 1) }, 4);
+
+    // The accumulator sees elements from head to tail.
+    unittest::assert_eq!(
+        unsafe { list.fold(0, |acc, element| acc * 10 + element.value) },
+        1234
+    );
+    Ok(())
+}
+
+#[test]
+fn pop_head_if_only_pops_matching_head() -> unittest::Result<()> {
+    let mut elements = [1, 2].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unittest::assert_true!(unsafe { list.pop_head_if(|_| true) }.is_none());
+
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    unittest::assert_true!(unsafe { list.pop_head_if(|element| element.value == 2) }.is_none());
+    unsafe { validate_list(&list, &[1, 2])? };
+
+    let popped = unsafe { list.pop_head_if(|element| element.value == 1) };
+    unittest::assert_eq!(popped.map(|element| element.value), Some(1));
+    unsafe { validate_list(&list, &[2]) }
+}
+
+#[test]
+fn pop_head_map_transforms_popped_head() -> unittest::Result<()> {
+    let mut elements = [1, 2].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    let mut calls = 0;
+    let mapped = unsafe {
+        list.pop_head_map(|element| {
+            calls += 1;
+            element.value
+        })
+    };
+    unittest::assert_eq!(mapped, None);
+    unittest::assert_eq!(calls, 0);
+
+    unsafe { list.extend_back(elements.iter_mut()) };
+    let mapped = unsafe {
+        list.pop_head_map(|element| {
+            calls += 1;
+            (element.value, element.link.is_unlinked())
+        })
+    };
+    unittest::assert_eq!(mapped, Some((1, true)));
+    unittest::assert_eq!(calls, 1);
+    unsafe { validate_list(&list, &[2]) }
+}
+
+#[test]
+fn drain_into_fills_array_from_head() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    let (drained, count) = unsafe { list.drain_into::<5>() };
+    unittest::assert_eq!(count, 3);
+    unittest::assert_eq!(
+        drained.map(|element| element.map(|element| (element.value, element.link.is_unlinked()))),
+        [
+            Some((1, true)),
+            Some((2, true)),
+            Some((3, true)),
+            None,
+            None
+        ]
+    );
+    unsafe { validate_list(&list, &[]) }
+}
+
+#[test]
+fn drain_into_leaves_excess_elements_linked() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4, 5].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    let (drained, count) = unsafe { list.drain_into::<3>() };
+    unittest::assert_eq!(count, 3);
+    unittest::assert_eq!(
+        drained.map(|element| element.map(|element| element.value)),
+        [Some(1), Some(2), Some(3)]
+    );
+    unsafe { validate_list(&list, &[4, 5]) }
+}
+
+#[test]
+fn iter_reports_remaining_length() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unittest::assert_eq!(unsafe { list.iter() }.len(), 0);
+
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    let mut iter = unsafe { list.iter() };
+    unittest::assert_eq!(iter.len(), 4);
+    iter.next();
+    unittest::assert_eq!(iter.len(), 3);
+    iter.next_back();
+    unittest::assert_eq!(iter.size_hint(), (2, Some(2)));
+    iter.next();
+    iter.next();
+    unittest::assert_eq!(iter.len(), 0);
+    unittest::assert_true!(iter.next().is_none());
+    unittest::assert_eq!(iter.len(), 0);
+    Ok(())
+}
+
+#[test]
+fn drain_reports_remaining_length() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    let mut drain = unsafe { list.drain() };
+    unittest::assert_eq!(drain.len(), 3);
+    drain.next();
+    unittest::assert_eq!(drain.len(), 2);
+    drain.next();
+    drain.next();
+    unittest::assert_eq!(drain.len(), 0);
+    Ok(())
+}
+
+#[test]
+fn unlink_returning_empty_reports_empty_list() -> unittest::Result<()> {
+    let mut elements = [1, 2].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+
+    let [element1, element2] = &elements;
+    unittest::assert_false!(unsafe { list.unlink_returning_empty(element2) });
+    unsafe { validate_list(&list, &[1])? };
+    unittest::assert_true!(unsafe { list.unlink_returning_empty(element1) });
+    unsafe { validate_list(&list, &[]) }
+}
+
+trait Schedulable {
+    fn priority(&self) -> u32;
+}
+
+// Links elements of different types through a common header, following the
+// pattern documented on `Adapter`.
+#[derive(Adapter)]
+#[repr(C)]
+struct TaskHeader {
+    #[list_link]
+    link: Link,
+    task: Option<NonNull<dyn Schedulable>>,
+}
+
+impl TaskHeader {
+    const fn new() -> Self {
+        Self {
+            link: Link::new(),
+            task: None,
+        }
+    }
+
+    fn get(&self) -> &dyn Schedulable {
+        let task = self.task.expect("header is not bound to a task");
+        // Safety: `task` is set before the header is pushed onto a list and
+        // points at the live task containing the header.
+        unsafe { task.as_ref() }
+    }
+}
+
+struct FixedTask {
+    priority: u32,
+    header: TaskHeader,
+}
+
+impl Schedulable for FixedTask {
+    fn priority(&self) -> u32 {
+        self.priority
+    }
+}
+
+struct BoostedTask {
+    header: TaskHeader,
+    base: u8,
+    boost: u8,
+}
+
+impl Schedulable for BoostedTask {
+    fn priority(&self) -> u32 {
+        u32::from(self.base) + u32::from(self.boost)
+    }
+}
+
+#[test]
+fn list_of_trait_objects_dispatches_through_header() -> unittest::Result<()> {
+    let mut fixed = FixedTask {
+        priority: 3,
+        header: TaskHeader::new(),
+    };
+    let mut boosted = BoostedTask {
+        header: TaskHeader::new(),
+        base: 4,
+        boost: 5,
+    };
+
+    let mut list = UnsafeList::<TaskHeader, TaskHeader>::new();
+
+    // The header of `FixedTask` is not its first field, which the pattern
+    // does not rely on.
+    let fixed_ptr = NonNull::from(&mut fixed);
+    let boosted_ptr = NonNull::from(&mut boosted);
+    unsafe {
+        (*fixed_ptr.as_ptr()).header.task = Some(fixed_ptr as NonNull<dyn Schedulable>);
+        list.push_back(&mut (*fixed_ptr.as_ptr()).header);
+        (*boosted_ptr.as_ptr()).header.task = Some(boosted_ptr as NonNull<dyn Schedulable>);
+        list.push_back(&mut (*boosted_ptr.as_ptr()).header);
+    }
+
+    let mut iter = unsafe { list.iter() };
+    unittest::assert_eq!(iter.next().map(|header| header.get().priority()), Some(3));
+    unittest::assert_eq!(iter.next().map(|header| header.get().priority()), Some(9));
+    unittest::assert_true!(iter.next().is_none());
+    Ok(())
+}
+
+#[test]
+fn count_between_counts_inclusive_range() -> unittest::Result<()> {
+    let mut elements = [1, 2, 3, 4, 5].map(|value| TestMember {
+        value,
+        link: Link::new(),
+    });
+
+    let mut list = UnsafeList::<TestMember, TestAdapter>::new();
+    unsafe { list.extend_back(elements.iter_mut()) };
+    let [element1, element2, _, element4, element5] = &elements;
+
+    unittest::assert_eq!(unsafe { list.count_between(element2, element4) }, 3);
+    unittest::assert_eq!(unsafe { list.count_between(element1, element5) }, 5);
+    unittest::assert_eq!(unsafe { list.count_between(element4, element4) }, 1);
+    Ok(())
+}
@@ -108,6 +108,19 @@ def make_crate_aliases(name):
         }),
         visibility = ["//visibility:public"],
     )
+    native.alias(
+        name = "defmt",
+        target_compatible_with = select({
+            ":no_std": [],
+            ":std": [],
+            "//conditions:default": ["@platforms//:incompatible"],
+        }),
+        actual = select({
+            ":no_std": "@crates_no_std//:defmt",
+            ":std": "@crates_std//:defmt",
+        }),
+        visibility = ["//visibility:public"],
+    )
     native.alias(
         name = "embedded-io",
         target_compatible_with = select({